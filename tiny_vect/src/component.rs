@@ -0,0 +1,26 @@
+// Numeric types accepted as components by the generic array conversions.
+// i32 is lossy above 2^24 but is kept for compatibility with the old
+// From<[i32; N]> impls. Sealed so no downstream type can add a lossy
+// conversion.
+pub trait Component: Copy + private::Sealed {
+    fn to_f32(self) -> f32;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_component {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+            impl Component for $t {
+                fn to_f32(self) -> f32 {
+                    self as f32
+                }
+            }
+        )*
+    };
+}
+
+impl_component!(f32, i8, u8, i16, u16, i32);
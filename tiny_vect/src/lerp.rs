@@ -0,0 +1,32 @@
+use crate::vect2::Vect2;
+use crate::vect3::Vect3;
+
+// Anything that can be linearly interpolated, so keyframe code can be
+// written once for scalars and vectors
+pub trait VectorLerp: Copy {
+    fn lerp_to(self, other: Self, t: f32) -> Self;
+}
+
+impl VectorLerp for f32 {
+    fn lerp_to(self, other: Self, t: f32) -> Self {
+        let result = self + (other - self) * t;
+        debug_assert!(result.is_finite(), "f32 lerp produced NaN or infinity");
+        result
+    }
+}
+
+impl VectorLerp for Vect2 {
+    fn lerp_to(self, other: Self, t: f32) -> Self {
+        self.lerp(&other, t)
+    }
+}
+
+impl VectorLerp for Vect3 {
+    fn lerp_to(self, other: Self, t: f32) -> Self {
+        self.lerp(&other, t)
+    }
+}
+
+pub fn lerp<V: VectorLerp>(a: V, b: V, t: f32) -> V {
+    a.lerp_to(b, t)
+}
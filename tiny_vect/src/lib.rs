@@ -1,6 +1,20 @@
 // lib.rs
+pub mod component;
+pub mod lerp;
+pub mod polar;
 pub mod vect2;
 pub mod vect3;
+pub mod vector;
 
-pub use vect2::Vect2;
-pub use vect3::Vect3;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_conv;
+#[cfg(feature = "serde")]
+pub mod serde_rounded;
+#[cfg(feature = "serde")]
+pub mod serde_tuple;
+
+pub use lerp::{VectorLerp, lerp};
+pub use polar::Polar;
+pub use vect2::{AreaAccumulator, Vect2};
+pub use vect3::{DisplaySlice, OrdVect3, Vect3};
+pub use vector::Vector;
@@ -0,0 +1,60 @@
+// Conversions to and from nalgebra's vector and point types
+use crate::vect2::Vect2;
+use crate::vect3::Vect3;
+use nalgebra::{Point2, Point3, Vector2, Vector3};
+
+impl From<Vector2<f32>> for Vect2 {
+    fn from(v: Vector2<f32>) -> Self {
+        Self { x: v.x, y: v.y }
+    }
+}
+
+impl From<Vect2> for Vector2<f32> {
+    fn from(v: Vect2) -> Self {
+        Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<Point2<f32>> for Vect2 {
+    fn from(p: Point2<f32>) -> Self {
+        Self { x: p.x, y: p.y }
+    }
+}
+
+impl From<Vect2> for Point2<f32> {
+    fn from(v: Vect2) -> Self {
+        Point2::new(v.x, v.y)
+    }
+}
+
+impl From<Vector3<f32>> for Vect3 {
+    fn from(v: Vector3<f32>) -> Self {
+        Self {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<Vect3> for Vector3<f32> {
+    fn from(v: Vect3) -> Self {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Point3<f32>> for Vect3 {
+    fn from(p: Point3<f32>) -> Self {
+        Self {
+            x: p.x,
+            y: p.y,
+            z: p.z,
+        }
+    }
+}
+
+impl From<Vect3> for Point3<f32> {
+    fn from(v: Vect3) -> Self {
+        Point3::new(v.x, v.y, v.z)
+    }
+}
@@ -0,0 +1,28 @@
+use crate::vect2::Vect2;
+use std::convert::From;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polar {
+    pub radius: f32,
+    pub angle: f32,
+}
+
+impl Polar {
+    pub fn new(radius: f32, angle: f32) -> Self {
+        Self { radius, angle }
+    }
+}
+
+impl From<Vect2> for Polar {
+    fn from(v: Vect2) -> Self {
+        let (radius, angle) = v.to_polar();
+        Self { radius, angle }
+    }
+}
+
+impl From<Polar> for Vect2 {
+    fn from(p: Polar) -> Self {
+        Vect2::from_polar(p.radius, p.angle)
+    }
+}
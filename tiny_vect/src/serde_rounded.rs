@@ -0,0 +1,26 @@
+// Serialize Vect3 with each component rounded to a fixed number of decimals,
+// so float jitter doesn't show up as diffs in text formats. Deserialization
+// is the regular Vect3 one.
+//
+//     #[serde(with = "tiny_vect::serde_rounded")]                  // 4 decimals
+//     #[serde(serialize_with = "tiny_vect::serde_rounded::serialize_decimals::<3, _>")]
+use crate::vect3::Vect3;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub fn serialize<S: Serializer>(v: &Vect3, serializer: S) -> Result<S::Ok, S::Error> {
+    serialize_decimals::<4, S>(v, serializer)
+}
+
+pub fn serialize_decimals<const DECIMALS: i32, S: Serializer>(
+    v: &Vect3,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    // Round in f64 so the scaled value doesn't pick up f32 error
+    let scale = 10f64.powi(DECIMALS);
+    let round = |c: f32| ((c as f64 * scale).round() / scale) as f32;
+    Vect3::new(round(v.x), round(v.y), round(v.z)).serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vect3, D::Error> {
+    Vect3::deserialize(deserializer)
+}
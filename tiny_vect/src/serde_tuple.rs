@@ -0,0 +1,12 @@
+// Serialize Vect3 as a plain (x, y, z) tuple, for use with #[serde(with = "...")]
+use crate::vect3::Vect3;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub fn serialize<S: Serializer>(v: &Vect3, serializer: S) -> Result<S::Ok, S::Error> {
+    (v.x, v.y, v.z).serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vect3, D::Error> {
+    let (x, y, z) = <(f32, f32, f32)>::deserialize(deserializer)?;
+    Ok(Vect3 { x, y, z })
+}
@@ -1,11 +1,15 @@
+use crate::component::Component;
 use std::convert::{From, TryFrom};
+use std::f32::consts::{PI, TAU};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::hash::{Hash, Hasher};
 use std::ops::{
-    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Range, Sub, SubAssign,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
 pub struct Vect2 {
     pub x: f32,
     pub y: f32,
@@ -16,6 +20,14 @@ impl Vect2 {
         Self { x, y }
     }
 
+    pub fn splat(v: f32) -> Self {
+        Self { x: v, y: v }
+    }
+
+    pub fn from_fn(f: impl Fn(usize) -> f32) -> Self {
+        Self { x: f(0), y: f(1) }
+    }
+
     pub fn length_squared(&self) -> f32 {
         let result = self.x * self.x + self.y * self.y;
         debug_assert!(
@@ -31,6 +43,17 @@ impl Vect2 {
         result
     }
 
+    pub fn length_f64(&self) -> f64 {
+        // Widen first so squaring can't overflow or lose precision in f32
+        let x = self.x as f64;
+        let y = self.y as f64;
+        (x * x + y * y).sqrt()
+    }
+
+    /// Returns a unit vector in the same direction. A zero-length input is
+    /// returned unchanged rather than as a unit vector; see
+    /// [`normalize_or_zero`](Self::normalize_or_zero) for a variant that
+    /// also tolerates non-finite input.
     pub fn normalize(&self) -> Self {
         // Compute squared length without any early debug_assert
         let sq = self.x * self.x + self.y * self.y;
@@ -56,18 +79,53 @@ impl Vect2 {
         }
     }
 
+    // Zero or non-finite input yields the zero vector; large finite input is
+    // pre-scaled so its length can't overflow
+    pub fn normalize_or_zero(&self) -> Self {
+        if !(self.x.is_finite() && self.y.is_finite()) {
+            return Self::default();
+        }
+        self.scaled_by_max_abs().normalize()
+    }
+
+    // `fallback` is returned as-is, so callers pass a unit vector
+    pub fn direction_or(&self, fallback: Self) -> Self {
+        let dir = self.normalize_or_zero();
+        if dir.is_zero() { fallback } else { dir }
+    }
+
     pub fn dot(&self, other: &Self) -> f32 {
         let result = self.x * other.x + self.y * other.y;
         debug_assert!(result.is_finite(), "Vect2::dot produced NaN or infinity");
         result
     }
 
+    pub fn dot_f64(&self, other: &Self) -> f64 {
+        self.x as f64 * other.x as f64 + self.y as f64 * other.y as f64
+    }
+
     pub fn cross(&self, other: &Self) -> f32 {
         let result = self.x * other.y - self.y * other.x;
         debug_assert!(result.is_finite(), "Vect2::cross produced NaN or infinity");
         result
     }
 
+    pub fn parallelogram_area(a: &Vect2, b: &Vect2) -> f32 {
+        a.cross(b).abs()
+    }
+
+    pub fn are_collinear(a: &Vect2, b: &Vect2, c: &Vect2, tol: f32) -> bool {
+        Self::parallelogram_area(&(*b - *a), &(*c - *a)) <= tol
+    }
+
+    // Broad-phase reject before exact segment tests; touching boxes overlap
+    pub fn segments_bbox_overlap(a1: &Vect2, a2: &Vect2, b1: &Vect2, b2: &Vect2) -> bool {
+        a1.x.min(a2.x) <= b1.x.max(b2.x)
+            && b1.x.min(b2.x) <= a1.x.max(a2.x)
+            && a1.y.min(a2.y) <= b1.y.max(b2.y)
+            && b1.y.min(b2.y) <= a1.y.max(a2.y)
+    }
+
     pub fn rotate(&self, angle: f32) -> Self {
         let cos = angle.cos();
         let sin = angle.sin();
@@ -80,6 +138,28 @@ impl Vect2 {
         Self { x, y }
     }
 
+    // (radius, angle) with the angle in radians from +x
+    pub fn to_polar(&self) -> (f32, f32) {
+        (self.length(), self.y.atan2(self.x))
+    }
+
+    pub fn from_polar(radius: f32, angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let result = Self {
+            x: radius * cos,
+            y: radius * sin,
+        };
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite(),
+            "Vect2::from_polar produced non-finite result"
+        );
+        result
+    }
+
+    pub fn to_polar_batch(vectors: &[Vect2]) -> Vec<(f32, f32)> {
+        vectors.iter().map(Vect2::to_polar).collect()
+    }
+
     pub fn distance(&self, other: &Self) -> f32 {
         let result = (*self - *other).length();
         debug_assert!(
@@ -118,6 +198,66 @@ impl Vect2 {
         Self { x, y }
     }
 
+    pub fn lerp_vec(&self, other: &Self, t: &Self) -> Self {
+        let x = self.x + (other.x - self.x) * t.x;
+        let y = self.y + (other.y - self.y) * t.y;
+        debug_assert!(
+            x.is_finite() && y.is_finite(),
+            "Vect2::lerp_vec produced non-finite result"
+        );
+        Self { x, y }
+    }
+
+    // Wraps into (-π, π]; in-range input is returned as-is, since the
+    // shift-and-rem round trip would cost it precision
+    pub fn wrap_angle(a: f32) -> f32 {
+        if a > -PI && a <= PI {
+            return a;
+        }
+        PI - (PI - a).rem_euclid(TAU)
+    }
+
+    // Wraps into [0, 2π)
+    pub fn wrap_angle_positive(a: f32) -> f32 {
+        let result = a.rem_euclid(TAU);
+        // rem_euclid can round up to exactly TAU for tiny negative inputs
+        if result >= TAU { 0.0 } else { result }
+    }
+
+    // Antiparallel inputs return the left-hand perpendicular of self
+    pub fn bisector(&self, other: &Self) -> Self {
+        let a = self.normalize_or_zero();
+        let result = (a + other.normalize_or_zero()).normalize_or_zero();
+        if result.is_zero() {
+            Self::new(-a.y, a.x)
+        } else {
+            result
+        }
+    }
+
+    // Interpolates along the shortest arc, so 3.0 -> -3.0 crosses π, not 0;
+    // the result is wrapped into (-π, π]
+    pub fn lerp_angle(a: f32, b: f32, t: f32) -> f32 {
+        let diff = Self::wrap_angle(b - a);
+        let result = Self::wrap_angle(a + diff * t);
+        debug_assert!(
+            result.is_finite(),
+            "Vect2::lerp_angle produced NaN or infinity"
+        );
+        result
+    }
+
+    pub fn slerp(&self, other: &Self, t: f32) -> Self {
+        let angle = Self::lerp_angle(self.y.atan2(self.x), other.y.atan2(other.x), t);
+        let len = self.length() + (other.length() - self.length()) * t;
+        let result = Self::new(angle.cos(), angle.sin()) * len;
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite(),
+            "Vect2::slerp produced non-finite result"
+        );
+        result
+    }
+
     pub fn reflect(&self, normal: &Self) -> Self {
         let normal = normal.normalize();
         let result = *self - normal * 2.0 * self.dot(&normal);
@@ -128,6 +268,54 @@ impl Vect2 {
         result
     }
 
+    // Also returns |cos| of the incidence angle, matching Vect3::reflect_with_cos
+    pub fn reflect_with_cos(&self, normal: &Self) -> (Self, f32) {
+        // Reuse the normalized normal and its dot for both outputs
+        let n = normal.normalize();
+        let dot = self.dot(&n);
+        let result = *self - n * (2.0 * dot);
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite(),
+            "Vect2::reflect_with_cos produced non-finite result"
+        );
+        let len = self.length();
+        let cos = if len == 0.0 { 0.0 } else { dot.abs() / len };
+        (result, cos)
+    }
+
+    // Mirrors a point across the infinite line through `point` along `direction`
+    pub fn reflect_across_line(&self, point: &Self, direction: &Self) -> Self {
+        debug_assert!(
+            !direction.is_zero(),
+            "Vect2::reflect_across_line: direction is zero"
+        );
+        let d = direction.normalize();
+        let rel = *self - *point;
+        let result = *point + d * (2.0 * rel.dot(&d)) - rel;
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite(),
+            "Vect2::reflect_across_line produced non-finite result"
+        );
+        result
+    }
+
+    // Snell's law; None on total internal reflection. Returns a unit direction.
+    pub fn refract(&self, normal: &Self, eta: f32) -> Option<Self> {
+        let i = self.normalize();
+        let n = normal.normalize();
+        let cos_i = i.dot(&n);
+        let k = 1.0 - eta * eta * (1.0 - cos_i * cos_i);
+        if k < 0.0 {
+            return None;
+        }
+        let result = i * eta - n * (eta * cos_i + k.sqrt());
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite(),
+            "Vect2::refract produced non-finite result"
+        );
+        Some(result)
+    }
+
     pub fn project(&self, other: &Self) -> Self {
         let len_sq = other.length_squared();
         if len_sq == 0.0 {
@@ -167,16 +355,280 @@ impl Vect2 {
         result
     }
 
+    // Signed exterior angle at curr in [-π, π]: positive for a left
+    // (counter-clockwise) turn. Collinear points give 0 when the path goes
+    // straight on and ±π when it doubles back (a U-turn is the sharpest corner)
+    pub fn turn_angle(prev: &Vect2, curr: &Vect2, next: &Vect2) -> f32 {
+        let incoming = *curr - *prev;
+        let outgoing = *next - *curr;
+        incoming.cross(&outgoing).atan2(incoming.dot(&outgoing))
+    }
+
     pub fn is_zero(&self) -> bool {
         self.x == 0.0 && self.y == 0.0
     }
 
+    pub fn is_near_zero(&self, eps: f32) -> bool {
+        self.x * self.x + self.y * self.y < eps * eps
+    }
+
+    pub fn is_approx_zero(&self) -> bool {
+        self.is_near_zero(1e-6)
+    }
+
     pub fn is_normalized(&self) -> bool {
-        (self.length_squared() - 1.0).abs() < f32::EPSILON
+        // normalize() itself leaves a few ULPs of error in length_squared
+        self.is_normalized_tol(1e-6)
+    }
+
+    pub fn is_normalized_tol(&self, tol: f32) -> bool {
+        (self.length_squared() - 1.0).abs() < tol
+    }
+
+    // Divides by the largest |component| so squares and products of the
+    // result can neither overflow nor underflow; zero and non-finite input
+    // are returned unchanged
+    fn scaled_by_max_abs(&self) -> Self {
+        let scale = self.x.abs().max(self.y.abs());
+        if scale == 0.0 || !scale.is_finite() {
+            return *self;
+        }
+        *self / scale
     }
 
     pub fn is_parallel(&self, other: &Self) -> bool {
-        self.cross(other).abs() < f32::EPSILON
+        // Compare unit directions so magnitude doesn't scale the threshold;
+        // pre-scaling keeps normalize exact for tiny and huge input
+        let a = self.scaled_by_max_abs().normalize();
+        let b = other.scaled_by_max_abs().normalize();
+        a.cross(&b).abs() < f32::EPSILON
+    }
+
+    pub fn is_parallel_tol(&self, other: &Self, angle_tol: f32) -> bool {
+        let angle = self
+            .scaled_by_max_abs()
+            .angle(&other.scaled_by_max_abs())
+            .abs();
+        angle <= angle_tol || PI - angle <= angle_tol
+    }
+
+    pub fn sum_elements(&self) -> f32 {
+        let result = self.x + self.y;
+        debug_assert!(
+            result.is_finite(),
+            "Vect2::sum_elements produced NaN or infinity"
+        );
+        result
+    }
+
+    pub fn product_elements(&self) -> f32 {
+        let result = self.x * self.y;
+        debug_assert!(
+            result.is_finite(),
+            "Vect2::product_elements produced NaN or infinity"
+        );
+        result
+    }
+
+    pub fn min_component(&self) -> f32 {
+        self.x.min(self.y)
+    }
+
+    pub fn max_component(&self) -> f32 {
+        self.x.max(self.y)
+    }
+
+    pub fn as_slice(&self) -> &[f32] {
+        // SAFETY: #[repr(C)] with only f32 fields, so the components are
+        // laid out contiguously with no padding. The pointer is derived from
+        // the whole struct, not from `x`, so it may read every component
+        unsafe { std::slice::from_raw_parts((self as *const Self).cast::<f32>(), 2) }
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut f32> {
+        [&mut self.x, &mut self.y].into_iter()
+    }
+
+    // Exact bit patterns, so one-ULP differences are visible
+    pub fn to_hex_string(&self) -> String {
+        format!("({:#010x}, {:#010x})", self.x.to_bits(), self.y.to_bits())
+    }
+
+    // e.g. format_with(",", false) gives CSV; Display stays "(x, y)"
+    pub fn format_with(&self, sep: &str, wrap: bool) -> String {
+        let body = format!("{}{}{}", self.x, sep, self.y);
+        if wrap { format!("({})", body) } else { body }
+    }
+
+    // NaN components pass through unchanged
+    pub fn clamp_scalar(&self, min: f32, max: f32) -> Self {
+        Self {
+            x: self.x.clamp(min, max),
+            y: self.y.clamp(min, max),
+        }
+    }
+
+    // NaN components are replaced with `nan` instead of passing through
+    pub fn clamp_scalar_nan(&self, min: f32, max: f32, nan: f32) -> Self {
+        let clamp = |c: f32| if c.is_nan() { nan } else { c.clamp(min, max) };
+        Self {
+            x: clamp(self.x),
+            y: clamp(self.y),
+        }
+    }
+
+    pub fn replace_nan(&self, value: f32) -> Self {
+        let replace = |c: f32| if c.is_nan() { value } else { c };
+        Self {
+            x: replace(self.x),
+            y: replace(self.y),
+        }
+    }
+
+    // Both signs of infinity are replaced
+    pub fn replace_inf(&self, value: f32) -> Self {
+        let replace = |c: f32| if c.is_infinite() { value } else { c };
+        Self {
+            x: replace(self.x),
+            y: replace(self.y),
+        }
+    }
+
+    pub fn clamp_min(&self, min: f32) -> Self {
+        Self {
+            x: self.x.max(min),
+            y: self.y.max(min),
+        }
+    }
+
+    pub fn clamp_max(&self, max: f32) -> Self {
+        Self {
+            x: self.x.min(max),
+            y: self.y.min(max),
+        }
+    }
+
+    pub fn clamp_min_vec(&self, min: &Self) -> Self {
+        Self {
+            x: self.x.max(min.x),
+            y: self.y.max(min.y),
+        }
+    }
+
+    pub fn clamp_max_vec(&self, max: &Self) -> Self {
+        Self {
+            x: self.x.min(max.x),
+            y: self.y.min(max.y),
+        }
+    }
+
+    // Zero cell components leave that axis unchanged
+    pub fn snap_to_grid(&self, cell: &Self) -> Self {
+        let snap = |c: f32, size: f32| {
+            if size == 0.0 {
+                c
+            } else {
+                (c / size).round() * size
+            }
+        };
+        Self {
+            x: snap(self.x, cell.x),
+            y: snap(self.y, cell.y),
+        }
+    }
+
+    pub fn snap_to_grid_uniform(&self, size: f32) -> Self {
+        self.snap_to_grid(&Self::splat(size))
+    }
+
+    pub fn eq_mask(&self, other: &Self) -> (bool, bool) {
+        (self.x == other.x, self.y == other.y)
+    }
+
+    pub fn approx_eq_mask(&self, other: &Self, eps: f32) -> (bool, bool) {
+        (
+            (self.x - other.x).abs() <= eps,
+            (self.y - other.y).abs() <= eps,
+        )
+    }
+
+    pub fn select(mask: (bool, bool), if_true: &Self, if_false: &Self) -> Self {
+        Self {
+            x: if mask.0 { if_true.x } else { if_false.x },
+            y: if mask.1 { if_true.y } else { if_false.y },
+        }
+    }
+
+    // Halves round away from zero
+    pub fn round(&self) -> Self {
+        Self {
+            x: self.x.round(),
+            y: self.y.round(),
+        }
+    }
+
+    // Halves round to the even neighbour (IEEE default)
+    pub fn round_ties_even(&self) -> Self {
+        Self {
+            x: self.x.round_ties_even(),
+            y: self.y.round_ties_even(),
+        }
+    }
+
+    // `as` saturates at the i32 bounds and maps NaN to 0
+    pub fn floor_to_i32(&self) -> [i32; 2] {
+        [self.x.floor() as i32, self.y.floor() as i32]
+    }
+
+    pub fn round_to_i32(&self) -> [i32; 2] {
+        [self.x.round() as i32, self.y.round() as i32]
+    }
+
+    pub fn exp2(&self) -> Self {
+        let result = Self {
+            x: self.x.exp2(),
+            y: self.y.exp2(),
+        };
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite(),
+            "Vect2::exp2 produced non-finite result"
+        );
+        result
+    }
+
+    pub fn log2(&self) -> Self {
+        let result = Self {
+            x: self.x.log2(),
+            y: self.y.log2(),
+        };
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite(),
+            "Vect2::log2 produced non-finite result"
+        );
+        result
+    }
+
+    pub fn abs_diff(&self, other: &Self) -> Self {
+        Self {
+            x: (self.x - other.x).abs(),
+            y: (self.y - other.y).abs(),
+        }
+    }
+
+    // Per component, whichever value has the larger magnitude, sign kept
+    pub fn max_abs(&self, other: &Self) -> Self {
+        let pick = |a: f32, b: f32| if a.abs() >= b.abs() { a } else { b };
+        Self {
+            x: pick(self.x, other.x),
+            y: pick(self.y, other.y),
+        }
+    }
+
+    pub fn flip(&self, flip_x: bool, flip_y: bool) -> Self {
+        Self {
+            x: if flip_x { -self.x } else { self.x },
+            y: if flip_y { -self.y } else { self.y },
+        }
     }
 }
 
@@ -218,6 +670,23 @@ impl Vect2 {
         );
         result
     }
+
+    // Clamp overflowed components to the finite range instead of infinity
+    pub fn saturating_add(self, other: Self) -> Self {
+        let result = self + other;
+        Self {
+            x: result.x.clamp(f32::MIN, f32::MAX),
+            y: result.y.clamp(f32::MIN, f32::MAX),
+        }
+    }
+
+    pub fn saturating_mul(self, scalar: f32) -> Self {
+        let result = self * scalar;
+        Self {
+            x: result.x.clamp(f32::MIN, f32::MAX),
+            y: result.y.clamp(f32::MIN, f32::MAX),
+        }
+    }
 }
 
 // Arithmetic operations
@@ -321,12 +790,19 @@ impl IndexMut<usize> for Vect2 {
     }
 }
 
+impl Index<Range<usize>> for Vect2 {
+    type Output = [f32];
+    fn index(&self, r: Range<usize>) -> &Self::Output {
+        &self.as_slice()[r]
+    }
+}
+
 // From conversions
-impl From<[f32; 2]> for Vect2 {
-    fn from(arr: [f32; 2]) -> Self {
+impl<T: Component> From<[T; 2]> for Vect2 {
+    fn from(arr: [T; 2]) -> Self {
         Self {
-            x: arr[0],
-            y: arr[1],
+            x: arr[0].to_f32(),
+            y: arr[1].to_f32(),
         }
     }
 }
@@ -340,15 +816,6 @@ impl From<(f32, f32)> for Vect2 {
     }
 }
 
-impl From<[i32; 2]> for Vect2 {
-    fn from(arr: [i32; 2]) -> Self {
-        Self {
-            x: arr[0] as f32,
-            y: arr[1] as f32,
-        }
-    }
-}
-
 impl From<(i32, i32)> for Vect2 {
     fn from(tuple: (i32, i32)) -> Self {
         Self {
@@ -408,3 +875,35 @@ impl Hash for Vect2 {
         state.write_u32(self.y.to_bits());
     }
 }
+
+// Streaming shoelace sum; only the first and latest points are kept
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AreaAccumulator {
+    first: Option<Vect2>,
+    last: Vect2,
+    sum: f32,
+}
+
+impl AreaAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, p: Vect2) {
+        if self.first.is_none() {
+            self.first = Some(p);
+        } else {
+            self.sum += self.last.cross(&p);
+        }
+        self.last = p;
+    }
+
+    // Signed (counter-clockwise positive); the closing edge back to the
+    // first point is added here, so pushing more points afterwards is fine
+    pub fn area(&self) -> f32 {
+        match self.first {
+            Some(first) => 0.5 * (self.sum + self.last.cross(&first)),
+            None => 0.0,
+        }
+    }
+}
@@ -1,11 +1,17 @@
+use crate::component::Component;
+use crate::vect2::Vect2;
+use std::cmp::Ordering;
 use std::convert::{From, TryFrom};
+use std::f32::consts::PI;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::hash::{Hash, Hasher};
 use std::ops::{
-    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Range, Sub, SubAssign,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
 pub struct Vect3 {
     pub x: f32,
     pub y: f32,
@@ -17,6 +23,40 @@ impl Vect3 {
         Self { x, y, z }
     }
 
+    pub fn splat(v: f32) -> Self {
+        Self { x: v, y: v, z: v }
+    }
+
+    pub fn from_fn(f: impl Fn(usize) -> f32) -> Self {
+        Self {
+            x: f(0),
+            y: f(1),
+            z: f(2),
+        }
+    }
+
+    // Deterministic pseudo-random unit vector (e.g. noise gradients), no RNG state
+    pub fn hash_to_unit(seed: u32) -> Self {
+        // lowbias32 integer hash
+        let hash = |mut h: u32| {
+            h ^= h >> 16;
+            h = h.wrapping_mul(0x7feb_352d);
+            h ^= h >> 15;
+            h = h.wrapping_mul(0x846c_a68b);
+            h ^ (h >> 16)
+        };
+        let h1 = hash(seed);
+        let h2 = hash(h1);
+        // Top 24 bits give exact floats in [0, 1)
+        let u = (h1 >> 8) as f32 / (1u32 << 24) as f32;
+        let v = (h2 >> 8) as f32 / (1u32 << 24) as f32;
+        // Uniform z and azimuth is uniform on the sphere
+        let z = 1.0 - 2.0 * u;
+        let r = (1.0 - z * z).max(0.0).sqrt();
+        let phi = 2.0 * PI * v;
+        Self::new(r * phi.cos(), r * phi.sin(), z)
+    }
+
     pub fn length_squared(&self) -> f32 {
         let result = self.x * self.x + self.y * self.y + self.z * self.z;
         debug_assert!(
@@ -32,6 +72,18 @@ impl Vect3 {
         result
     }
 
+    pub fn length_f64(&self) -> f64 {
+        // Widen first so squaring can't overflow or lose precision in f32
+        let x = self.x as f64;
+        let y = self.y as f64;
+        let z = self.z as f64;
+        (x * x + y * y + z * z).sqrt()
+    }
+
+    /// Returns a unit vector in the same direction. A zero-length input is
+    /// returned unchanged rather than as a unit vector; see
+    /// [`normalize_or_zero`](Self::normalize_or_zero) for a variant that
+    /// also tolerates non-finite input.
     pub fn normalize(&self) -> Self {
         let len = self.length();
         debug_assert!(len >= 0.0, "Vect3::normalize: length negative (impossible)");
@@ -47,12 +99,41 @@ impl Vect3 {
         }
     }
 
+    // Same contract as safe_normalize, which it forwards to: zero or
+    // non-finite input yields the zero vector, large input can't overflow
+    pub fn normalize_or_zero(&self) -> Self {
+        self.safe_normalize()
+    }
+
+    // Divides by the largest |component| first so length_squared can't
+    // overflow (or underflow for tiny input); zero is returned unchanged and
+    // non-finite input yields zero
+    pub fn safe_normalize(&self) -> Self {
+        if !(self.x.is_finite() && self.y.is_finite() && self.z.is_finite()) {
+            return Self::default();
+        }
+        self.scaled_by_max_abs().normalize()
+    }
+
+    // `fallback` is returned as-is, so callers pass a unit vector
+    pub fn direction_or(&self, fallback: Self) -> Self {
+        let dir = self.normalize_or_zero();
+        if dir.is_zero() { fallback } else { dir }
+    }
+
     pub fn dot(&self, other: &Self) -> f32 {
         let result = self.x * other.x + self.y * other.y + self.z * other.z;
         debug_assert!(result.is_finite(), "Vect3::dot produced NaN or infinity");
         result
     }
 
+    // Widened to f64 so products don't cancel away in large-coordinate tests
+    pub fn dot_f64(&self, other: &Self) -> f64 {
+        self.x as f64 * other.x as f64
+            + self.y as f64 * other.y as f64
+            + self.z as f64 * other.z as f64
+    }
+
     pub fn cross(&self, other: &Self) -> Self {
         let x = self.y * other.z - self.z * other.y;
         let y = self.z * other.x - self.x * other.z;
@@ -64,6 +145,83 @@ impl Vect3 {
         Self { x, y, z }
     }
 
+    // Signed volume of the parallelepiped spanned by a, b, c
+    pub fn scalar_triple(a: &Vect3, b: &Vect3, c: &Vect3) -> f32 {
+        let result = a.dot(&b.cross(c));
+        debug_assert!(
+            result.is_finite(),
+            "Vect3::scalar_triple produced NaN or infinity"
+        );
+        result
+    }
+
+    // |(b - a) × (c - a)| is twice the triangle area, so tol is an area bound
+    pub fn are_collinear(a: &Vect3, b: &Vect3, c: &Vect3, tol: f32) -> bool {
+        (*b - *a).cross(&(*c - *a)).length() <= tol
+    }
+
+    // Unnormalized triple product, so collinear or repeated points give 0, not NaN
+    pub fn are_coplanar(a: &Vect3, b: &Vect3, c: &Vect3, d: &Vect3, tol: f32) -> bool {
+        Self::scalar_triple(&(*b - *a), &(*c - *a), &(*d - *a)).abs() <= tol
+    }
+
+    // Signed; positive when (b - a, c - a, d - a) form a right-handed frame
+    pub fn tetrahedron_volume(a: &Vect3, b: &Vect3, c: &Vect3, d: &Vect3) -> f32 {
+        Self::scalar_triple(&(*b - *a), &(*c - *a), &(*d - *a)) / 6.0
+    }
+
+    // Clamped-parameter solve; degenerate and parallel segments fall back to
+    // endpoint projections so no division by a zero length happens
+    pub fn closest_points_on_segments(
+        p1: &Vect3,
+        q1: &Vect3,
+        p2: &Vect3,
+        q2: &Vect3,
+    ) -> (Vect3, Vect3) {
+        let d1 = *q1 - *p1;
+        let d2 = *q2 - *p2;
+        let r = *p1 - *p2;
+        let a = d1.length_squared();
+        let e = d2.length_squared();
+        let f = d2.dot(&r);
+
+        if a <= f32::EPSILON && e <= f32::EPSILON {
+            return (*p1, *p2);
+        }
+        let (s, t) = if a <= f32::EPSILON {
+            (0.0, (f / e).clamp(0.0, 1.0))
+        } else {
+            let c = d1.dot(&r);
+            if e <= f32::EPSILON {
+                ((-c / a).clamp(0.0, 1.0), 0.0)
+            } else {
+                let b = d1.dot(&d2);
+                let denom = a * e - b * b;
+                let s = if denom > f32::EPSILON * a * e {
+                    ((b * f - c * e) / denom).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                let t = (b * s + f) / e;
+                if t < 0.0 {
+                    ((-c / a).clamp(0.0, 1.0), 0.0)
+                } else if t > 1.0 {
+                    (((b - c) / a).clamp(0.0, 1.0), 1.0)
+                } else {
+                    (s, t)
+                }
+            }
+        };
+        (*p1 + d1 * s, *p2 + d2 * t)
+    }
+
+    // |(p - line_point) × dir| / |dir|, so dir need not be normalized
+    pub fn distance_to_line(p: &Vect3, line_point: &Vect3, line_dir: &Vect3) -> f32 {
+        let len = line_dir.length();
+        debug_assert!(len != 0.0, "Vect3::distance_to_line direction is zero");
+        (*p - *line_point).cross(line_dir).length() / len
+    }
+
     pub fn distance(&self, other: &Self) -> f32 {
         let result = (*self - *other).length();
         debug_assert!(
@@ -118,6 +276,26 @@ impl Vect3 {
         Self { x, y, z }
     }
 
+    pub fn lerp_vec(&self, other: &Self, t: &Self) -> Self {
+        let x = self.x + (other.x - self.x) * t.x;
+        let y = self.y + (other.y - self.y) * t.y;
+        let z = self.z + (other.z - self.z) * t.z;
+        debug_assert!(
+            x.is_finite() && y.is_finite() && z.is_finite(),
+            "Vect3::lerp_vec produced non-finite result"
+        );
+        Self { x, y, z }
+    }
+
+    // Exponential moving average; alpha is clamped to [0, 1]
+    pub fn ema(&self, sample: &Self, alpha: f32) -> Self {
+        self.lerp(sample, alpha.clamp(0.0, 1.0))
+    }
+
+    pub fn ema_update(&mut self, sample: &Self, alpha: f32) {
+        *self = self.ema(sample, alpha);
+    }
+
     pub fn reflect(&self, normal: &Self) -> Self {
         let n = normal.normalize();
         let dot = self.dot(&n);
@@ -129,6 +307,133 @@ impl Vect3 {
         result
     }
 
+    // Splits into tangential and normal parts in f64 and flips the normal part;
+    // one rounding per component instead of reflect's chain of f32 roundings
+    pub fn reflect_precise(&self, normal: &Self) -> Self {
+        let n = normal.normalize();
+        let d = self.dot_f64(&n);
+        let flip = |c: f32, nc: f32| {
+            let normal_part = nc as f64 * d;
+            let tangential = c as f64 - normal_part;
+            (tangential - normal_part) as f32
+        };
+        let result = Self {
+            x: flip(self.x, n.x),
+            y: flip(self.y, n.y),
+            z: flip(self.z, n.z),
+        };
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::reflect_precise produced non-finite result"
+        );
+        result
+    }
+
+    // Same as reflect, but folds the normalization into one division (no sqrt)
+    pub fn reflect_unnormalized(&self, normal: &Self) -> Self {
+        let len_sq = normal.length_squared();
+        if len_sq == 0.0 {
+            return *self;
+        }
+        let result = *self - *normal * (2.0 * self.dot(normal) / len_sq);
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::reflect_unnormalized produced non-finite result"
+        );
+        result
+    }
+
+    // Also returns the incidence cosine |dir · n|, e.g. for Fresnel terms
+    pub fn reflect_with_cos(&self, normal: &Self) -> (Self, f32) {
+        // Reuse the normalized normal and its dot for both outputs
+        let n = normal.normalize();
+        let dot = self.dot(&n);
+        let result = *self - n * (2.0 * dot);
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::reflect_with_cos produced non-finite result"
+        );
+        let len = self.length();
+        let cos = if len == 0.0 { 0.0 } else { dot.abs() / len };
+        (result, cos)
+    }
+
+    // Unlike reflect_with_cos this is one-sided: back-facing hits give 0
+    pub fn reflect_split(&self, normal: &Self) -> (Self, f32) {
+        let n = normal.normalize();
+        let dot = self.dot(&n);
+        let result = *self - n * (2.0 * dot);
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::reflect_split produced non-finite result"
+        );
+        let len = self.length();
+        let cos = if len == 0.0 {
+            0.0
+        } else {
+            (-dot / len).max(0.0)
+        };
+        (result, cos)
+    }
+
+    // Mirrors a point (not a direction) across the plane through plane_point
+    pub fn mirror_across_plane(&self, plane_point: &Self, plane_normal: &Self) -> Self {
+        let n = plane_normal.normalize();
+        let d = (*self - *plane_point).dot(&n);
+        let result = *self - n * (2.0 * d);
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::mirror_across_plane produced non-finite result"
+        );
+        result
+    }
+
+    // Normalizes once and reflects every velocity in place
+    pub fn reflect_slice(velocities: &mut [Vect3], normal: &Vect3) {
+        let n = normal.normalize();
+        for v in velocities.iter_mut() {
+            *v -= n * (2.0 * v.dot(&n));
+            debug_assert!(
+                v.x.is_finite() && v.y.is_finite() && v.z.is_finite(),
+                "Vect3::reflect_slice produced non-finite result"
+            );
+        }
+    }
+
+    // Normals are assumed unit; out[i] receives incident reflected off normals[i]
+    pub fn reflect_against_normals(incident: &Vect3, normals: &[Vect3], out: &mut [Vect3]) {
+        debug_assert!(
+            normals.len() == out.len(),
+            "Vect3::reflect_against_normals: normals and out differ in length"
+        );
+        for (n, o) in normals.iter().zip(out.iter_mut()) {
+            debug_assert!(
+                n.is_normalized(),
+                "Vect3::reflect_against_normals: normal is not normalized"
+            );
+            *o = *incident - *n * (2.0 * incident.dot(n));
+        }
+    }
+
+    // Reflects only the normal component, scaled by restitution
+    pub fn bounce(&self, normal: &Self, restitution: f32) -> Self {
+        self.collide(normal, restitution, 0.0)
+    }
+
+    // Contact response: the normal component is reversed and scaled by
+    // restitution, the tangential one is reduced by friction in [0, 1]
+    pub fn collide(&self, normal: &Self, restitution: f32, friction: f32) -> Self {
+        let n = normal.normalize();
+        let vn = n * self.dot(&n);
+        let vt = *self - vn;
+        let result = vt * (1.0 - friction.clamp(0.0, 1.0)) - vn * restitution;
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::collide produced non-finite result"
+        );
+        result
+    }
+
     pub fn project(&self, other: &Self) -> Self {
         let len_sq = other.length_squared();
         if len_sq == 0.0 {
@@ -144,6 +449,444 @@ impl Vect3 {
         }
     }
 
+    // Out-parameter variants for hot loops
+    pub fn add_into(&self, other: &Self, out: &mut Self) {
+        out.x = self.x + other.x;
+        out.y = self.y + other.y;
+        out.z = self.z + other.z;
+    }
+
+    pub fn sub_into(&self, other: &Self, out: &mut Self) {
+        out.x = self.x - other.x;
+        out.y = self.y - other.y;
+        out.z = self.z - other.z;
+    }
+
+    pub fn project_into(&self, other: &Self, out: &mut Self) {
+        *out = self.project(other);
+    }
+
+    // Skips the length_squared division when the target is already unit
+    pub fn project_onto_unit(&self, unit: &Self) -> Self {
+        debug_assert!(
+            unit.is_normalized(),
+            "Vect3::project_onto_unit: target is not normalized"
+        );
+        let result = *unit * self.dot(unit);
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::project_onto_unit produced non-finite result"
+        );
+        result
+    }
+
+    // Rotations
+    pub fn rotate_around_axis(&self, axis: &Self, angle: f32) -> Self {
+        // Rodrigues' rotation formula
+        let k = axis.normalize();
+        let (sin, cos) = angle.sin_cos();
+        let result = *self * cos + k.cross(self) * sin + k * (k.dot(self) * (1.0 - cos));
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::rotate_around_axis produced non-finite result"
+        );
+        result
+    }
+
+    pub fn rotate_x(&self, angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let y = self.y * cos - self.z * sin;
+        let z = self.y * sin + self.z * cos;
+        debug_assert!(
+            y.is_finite() && z.is_finite(),
+            "Vect3::rotate_x produced non-finite result"
+        );
+        Self { x: self.x, y, z }
+    }
+
+    pub fn rotate_y(&self, angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let x = self.x * cos + self.z * sin;
+        let z = -self.x * sin + self.z * cos;
+        debug_assert!(
+            x.is_finite() && z.is_finite(),
+            "Vect3::rotate_y produced non-finite result"
+        );
+        Self { x, y: self.y, z }
+    }
+
+    pub fn rotate_z(&self, angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let x = self.x * cos - self.y * sin;
+        let y = self.x * sin + self.y * cos;
+        debug_assert!(
+            x.is_finite() && y.is_finite(),
+            "Vect3::rotate_z produced non-finite result"
+        );
+        Self { x, y, z: self.z }
+    }
+
+    // Keeps self's magnitude; only the direction turns toward target
+    pub fn rotate_towards(&self, target: &Self, max_angle: f32) -> Self {
+        if target.is_zero() {
+            return *self;
+        }
+        let angle = self.angle_between(target);
+        if angle <= max_angle {
+            return target.normalize() * self.length();
+        }
+        let mut axis = self.cross(target);
+        if axis.length_squared() == 0.0 {
+            // Antiparallel: any perpendicular axis gives a shortest arc
+            axis = self.any_orthogonal();
+        }
+        self.rotate_around_axis(&axis, max_angle)
+    }
+
+    // Rotates the point about an axis passing through `center`
+    pub fn orbit(&self, center: &Self, axis: &Self, angle: f32) -> Self {
+        (*self - *center).rotate_around_axis(axis, angle) + *center
+    }
+
+    // Antiparallel inputs have no unique bisector; any perpendicular is returned
+    pub fn bisector(&self, other: &Self) -> Self {
+        let a = self.normalize_or_zero();
+        let result = (a + other.normalize_or_zero()).normalize_or_zero();
+        if result.is_zero() {
+            a.any_orthogonal()
+        } else {
+            result
+        }
+    }
+
+    fn any_orthogonal(&self) -> Self {
+        // Cross with the axis least aligned with self
+        let other = if self.x.abs() <= self.y.abs() && self.x.abs() <= self.z.abs() {
+            Vect3::new(1.0, 0.0, 0.0)
+        } else if self.y.abs() <= self.z.abs() {
+            Vect3::new(0.0, 1.0, 0.0)
+        } else {
+            Vect3::new(0.0, 0.0, 1.0)
+        };
+        self.cross(&other).normalize()
+    }
+
+    // Tangent space
+    pub fn tangent_basis(
+        p0: &Vect3,
+        p1: &Vect3,
+        p2: &Vect3,
+        uv0: &Vect2,
+        uv1: &Vect2,
+        uv2: &Vect2,
+    ) -> (Vect3, Vect3, Vect3) {
+        let e1 = *p1 - *p0;
+        let e2 = *p2 - *p0;
+        let duv1 = *uv1 - *uv0;
+        let duv2 = *uv2 - *uv0;
+
+        let mut n = e1.cross(&e2).normalize();
+        if n.is_zero() {
+            n = Vect3::new(0.0, 0.0, 1.0);
+        }
+
+        // Relative to the UV edge lengths, so texel-scale triangles with
+        // small but well-conditioned UV deltas aren't treated as degenerate
+        let det = duv1.cross(&duv2);
+        let r = 1.0 / det;
+        if det.abs() <= f32::EPSILON * duv1.length() * duv2.length() || !r.is_finite() {
+            // Degenerate UVs: any orthonormal frame around the normal
+            let t = n.any_orthogonal();
+            return (t, n.cross(&t), n);
+        }
+
+        let t = (e1 * duv2.y - e2 * duv1.y) * r;
+        let b = (e2 * duv1.x - e1 * duv2.x) * r;
+
+        // Gram-Schmidt against the normal, keeping the UV handedness
+        let t = (t - n * n.dot(&t)).normalize();
+        if t.is_zero() {
+            // Coincident or collinear positions leave no tangent in the plane
+            let t = n.any_orthogonal();
+            return (t, n.cross(&t), n);
+        }
+        let b_ortho = n.cross(&t);
+        let b = if b_ortho.dot(&b) < 0.0 {
+            -b_ortho
+        } else {
+            b_ortho
+        };
+        debug_assert!(
+            t.x.is_finite() && t.y.is_finite() && t.z.is_finite(),
+            "Vect3::tangent_basis produced non-finite result"
+        );
+        (t, b, n)
+    }
+
+    // Curves
+    pub fn hermite(p0: &Vect3, m0: &Vect3, p1: &Vect3, m1: &Vect3, t: f32) -> Vect3 {
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = -2.0 * t3 + 3.0 * t2;
+        let h11 = t3 - t2;
+        let result = *p0 * h00 + *m0 * h10 + *p1 * h01 + *m1 * h11;
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::hermite produced non-finite result"
+        );
+        result
+    }
+
+    // Uniform Catmull-Rom segment between p1 and p2
+    pub fn catmull_rom(p0: &Vect3, p1: &Vect3, p2: &Vect3, p3: &Vect3, t: f32) -> Vect3 {
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let result = (*p1 * 2.0
+            + (*p2 - *p0) * t
+            + (*p0 * 2.0 - *p1 * 5.0 + *p2 * 4.0 - *p3) * t2
+            + (*p1 * 3.0 - *p0 - *p2 * 3.0 + *p3) * t3)
+            * 0.5;
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::catmull_rom produced non-finite result"
+        );
+        result
+    }
+
+    // Endpoints are duplicated so the curve passes through every point
+    pub fn catmull_rom_spline(points: &[Vect3], samples_per_segment: usize) -> Vec<Vect3> {
+        if points.len() < 2 {
+            return points.to_vec();
+        }
+        let samples = samples_per_segment.max(1);
+        let last = points.len() - 1;
+        let mut result = Vec::with_capacity(last * samples + 1);
+        for i in 0..last {
+            let p0 = &points[i.saturating_sub(1)];
+            let p1 = &points[i];
+            let p2 = &points[i + 1];
+            let p3 = &points[(i + 2).min(last)];
+            for s in 0..samples {
+                let t = s as f32 / samples as f32;
+                result.push(Self::catmull_rom(p0, p1, p2, p3, t));
+            }
+        }
+        result.push(points[last]);
+        result
+    }
+
+    // n points from a to b inclusive
+    pub fn linspace(a: &Vect3, b: &Vect3, n: usize) -> Vec<Vect3> {
+        match n {
+            0 => Vec::new(),
+            1 => vec![*a],
+            _ => (0..n)
+                .map(|i| a.lerp(b, i as f32 / (n - 1) as f32))
+                .collect(),
+        }
+    }
+
+    // Arc length
+
+    // Consecutive (start, end) pairs of a polyline
+    pub fn segments(points: &[Vect3]) -> impl Iterator<Item = (Vect3, Vect3)> + '_ {
+        points.windows(2).map(|w| (w[0], w[1]))
+    }
+
+    pub fn curve_arc_length(samples: &[Vect3]) -> f32 {
+        let result = Self::segments(samples)
+            .map(|(a, b)| a.distance(&b))
+            .sum::<f32>();
+        debug_assert!(
+            result.is_finite(),
+            "Vect3::curve_arc_length produced NaN or infinity"
+        );
+        result
+    }
+
+    pub fn reparameterize_by_arc_length(
+        curve_fn: impl Fn(f32) -> Vect3,
+        segments: usize,
+    ) -> Vec<f32> {
+        if segments == 0 {
+            return vec![0.0];
+        }
+        // Cumulative length table over uniformly sampled t
+        let mut table = Vec::with_capacity(segments + 1);
+        let mut prev = curve_fn(0.0);
+        let mut total = 0.0;
+        table.push(0.0);
+        for i in 1..=segments {
+            let p = curve_fn(i as f32 / segments as f32);
+            total += prev.distance(&p);
+            table.push(total);
+            prev = p;
+        }
+        if total == 0.0 {
+            return (0..=segments).map(|i| i as f32 / segments as f32).collect();
+        }
+
+        // Invert the table so consecutive t-values are equal arc length apart
+        let mut result = Vec::with_capacity(segments + 1);
+        let mut j = 0;
+        for i in 0..=segments {
+            let target = total * i as f32 / segments as f32;
+            while j + 1 < segments && table[j + 1] < target {
+                j += 1;
+            }
+            let span = table[j + 1] - table[j];
+            let frac = if span == 0.0 {
+                0.0
+            } else {
+                ((target - table[j]) / span).clamp(0.0, 1.0)
+            };
+            result.push((j as f32 + frac) / segments as f32);
+        }
+        result
+    }
+
+    // Spatial partitioning
+    pub fn component(&self, axis: usize) -> f32 {
+        self[axis]
+    }
+
+    // Axis with the largest extent, for choosing a kd-tree split plane
+    pub fn split_axis(points: &[Vect3]) -> usize {
+        let Some(first) = points.first() else {
+            return 0;
+        };
+        let (min, max) = points.iter().fold((*first, *first), |(lo, hi), p| {
+            (
+                Vect3::new(lo.x.min(p.x), lo.y.min(p.y), lo.z.min(p.z)),
+                Vect3::new(hi.x.max(p.x), hi.y.max(p.y), hi.z.max(p.z)),
+            )
+        });
+        let extent = max - min;
+        if extent.x >= extent.y && extent.x >= extent.z {
+            0
+        } else if extent.y >= extent.z {
+            1
+        } else {
+            2
+        }
+    }
+
+    // Flat [x0, y0, z0, x1, ...] layout, e.g. for GPU upload
+    pub fn to_flat_vec(vectors: &[Vect3]) -> Vec<f32> {
+        vectors.iter().flat_map(|v| [v.x, v.y, v.z]).collect()
+    }
+
+    // Index and squared distance of the closest candidate
+    pub fn nearest(&self, candidates: &[Vect3]) -> Option<(usize, f32)> {
+        candidates
+            .iter()
+            .map(|c| self.distance_squared(c))
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    pub fn to_array_vec(vectors: &[Vect3]) -> Vec<[f32; 3]> {
+        vectors.iter().map(|v| [v.x, v.y, v.z]).collect()
+    }
+
+    pub fn from_array_slice(arrays: &[[f32; 3]]) -> Vec<Vect3> {
+        arrays.iter().map(|a| Vect3::from(*a)).collect()
+    }
+
+    // Weighted blends
+    pub fn weighted_sum(vectors: &[Vect3], weights: &[f32]) -> Vect3 {
+        debug_assert!(
+            vectors.len() == weights.len(),
+            "Vect3::weighted_sum: vectors and weights differ in length"
+        );
+        let result = vectors
+            .iter()
+            .zip(weights)
+            .fold(Vect3::default(), |acc, (v, w)| acc + *v * *w);
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::weighted_sum produced non-finite result"
+        );
+        result
+    }
+
+    pub fn weighted_average(vectors: &[Vect3], weights: &[f32]) -> Vect3 {
+        let total: f32 = weights.iter().take(vectors.len()).sum();
+        if total == 0.0 {
+            return Vect3::default();
+        }
+        Self::weighted_sum(vectors, weights) / total
+    }
+
+    // Statistics
+    pub fn mean(points: &[Vect3]) -> Vect3 {
+        if points.is_empty() {
+            return Vect3::default();
+        }
+        let sum = points.iter().fold(Vect3::default(), |acc, p| acc + *p);
+        let result = sum / points.len() as f32;
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::mean produced non-finite result"
+        );
+        result
+    }
+
+    // Running mean that divides before subtracting (mean += p / n - mean / n),
+    // so neither the sum nor p - mean is ever formed and opposite-sign
+    // extremes can't overflow
+    pub fn stable_centroid(points: &[Vect3]) -> Vect3 {
+        let mut mean = Vect3::default();
+        for (i, p) in points.iter().enumerate() {
+            let n = (i + 1) as f32;
+            mean += *p / n - mean / n;
+        }
+        debug_assert!(
+            mean.x.is_finite() && mean.y.is_finite() && mean.z.is_finite(),
+            "Vect3::stable_centroid produced non-finite result"
+        );
+        mean
+    }
+
+    // Per-component population variance
+    pub fn variance(points: &[Vect3]) -> Vect3 {
+        if points.len() < 2 {
+            return Vect3::default();
+        }
+        let mean = Self::mean(points);
+        let sum = points.iter().fold(Vect3::default(), |acc, p| {
+            let d = *p - mean;
+            acc + Vect3::new(d.x * d.x, d.y * d.y, d.z * d.z)
+        });
+        let result = sum / points.len() as f32;
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::variance produced non-finite result"
+        );
+        result
+    }
+
+    // Gradient of a scalar field by central differences along each axis
+    pub fn central_difference(f: impl Fn(Vect3) -> f32, at: Vect3, h: f32) -> Vect3 {
+        debug_assert!(h != 0.0, "Vect3::central_difference: step is zero");
+        let dx = Vect3::new(h, 0.0, 0.0);
+        let dy = Vect3::new(0.0, h, 0.0);
+        let dz = Vect3::new(0.0, 0.0, h);
+        let inv = 0.5 / h;
+        let result = Vect3::new(
+            (f(at + dx) - f(at - dx)) * inv,
+            (f(at + dy) - f(at - dy)) * inv,
+            (f(at + dz) - f(at - dz)) * inv,
+        );
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::central_difference produced non-finite result"
+        );
+        result
+    }
+
     // Checked operations in debug
     pub fn debug_checked_add(self, other: Self) -> Self {
         let result = self + other;
@@ -182,17 +925,425 @@ impl Vect3 {
         result
     }
 
+    // Clamp overflowed components to the finite range instead of infinity
+    pub fn saturating_add(self, other: Self) -> Self {
+        let result = self + other;
+        Self {
+            x: result.x.clamp(f32::MIN, f32::MAX),
+            y: result.y.clamp(f32::MIN, f32::MAX),
+            z: result.z.clamp(f32::MIN, f32::MAX),
+        }
+    }
+
+    pub fn saturating_mul(self, scalar: f32) -> Self {
+        let result = self * scalar;
+        Self {
+            x: result.x.clamp(f32::MIN, f32::MAX),
+            y: result.y.clamp(f32::MIN, f32::MAX),
+            z: result.z.clamp(f32::MIN, f32::MAX),
+        }
+    }
+
     // Utility methods
     pub fn is_zero(&self) -> bool {
         self.x == 0.0 && self.y == 0.0 && self.z == 0.0
     }
 
+    pub fn is_near_zero(&self, eps: f32) -> bool {
+        self.x * self.x + self.y * self.y + self.z * self.z < eps * eps
+    }
+
+    pub fn is_approx_zero(&self) -> bool {
+        self.is_near_zero(1e-6)
+    }
+
     pub fn is_normalized(&self) -> bool {
-        (self.length_squared() - 1.0).abs() < f32::EPSILON
+        // normalize() itself leaves a few ULPs of error in length_squared
+        self.is_normalized_tol(1e-6)
+    }
+
+    pub fn is_normalized_tol(&self, tol: f32) -> bool {
+        (self.length_squared() - 1.0).abs() < tol
+    }
+
+    // Divides by the largest |component| so squares and products of the
+    // result can neither overflow nor underflow; zero and non-finite input
+    // are returned unchanged
+    fn scaled_by_max_abs(&self) -> Self {
+        let scale = self.x.abs().max(self.y.abs()).max(self.z.abs());
+        if scale == 0.0 || !scale.is_finite() {
+            return *self;
+        }
+        *self / scale
     }
 
     pub fn is_parallel(&self, other: &Self) -> bool {
-        self.cross(other).length_squared().abs() < f32::EPSILON
+        // Compare unit directions so magnitude doesn't scale the threshold;
+        // pre-scaling keeps normalize exact for tiny and huge input
+        let a = self.scaled_by_max_abs().normalize();
+        let b = other.scaled_by_max_abs().normalize();
+        a.cross(&b).length_squared() < f32::EPSILON
+    }
+
+    pub fn is_parallel_tol(&self, other: &Self, angle_tol: f32) -> bool {
+        // atan2 stays accurate near 0 and π where acos does not
+        let a = self.scaled_by_max_abs();
+        let b = other.scaled_by_max_abs();
+        let angle = a.cross(&b).length().atan2(a.dot(&b));
+        debug_assert!(
+            angle.is_finite(),
+            "Vect3::is_parallel_tol produced NaN or infinity"
+        );
+        angle <= angle_tol || PI - angle <= angle_tol
+    }
+
+    pub fn sum_elements(&self) -> f32 {
+        let result = self.x + self.y + self.z;
+        debug_assert!(
+            result.is_finite(),
+            "Vect3::sum_elements produced NaN or infinity"
+        );
+        result
+    }
+
+    pub fn product_elements(&self) -> f32 {
+        let result = self.x * self.y * self.z;
+        debug_assert!(
+            result.is_finite(),
+            "Vect3::product_elements produced NaN or infinity"
+        );
+        result
+    }
+
+    pub fn min_component(&self) -> f32 {
+        self.x.min(self.y).min(self.z)
+    }
+
+    pub fn max_component(&self) -> f32 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    pub fn as_slice(&self) -> &[f32] {
+        // SAFETY: #[repr(C)] with only f32 fields, so the components are
+        // laid out contiguously with no padding. The pointer is derived from
+        // the whole struct, not from `x`, so it may read every component
+        unsafe { std::slice::from_raw_parts((self as *const Self).cast::<f32>(), 3) }
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut f32> {
+        [&mut self.x, &mut self.y, &mut self.z].into_iter()
+    }
+
+    // Same bits Hash uses; round-trips exactly, NaN payloads included
+    pub fn to_bits(&self) -> [u32; 3] {
+        [self.x.to_bits(), self.y.to_bits(), self.z.to_bits()]
+    }
+
+    pub fn from_bits(bits: [u32; 3]) -> Self {
+        Self {
+            x: f32::from_bits(bits[0]),
+            y: f32::from_bits(bits[1]),
+            z: f32::from_bits(bits[2]),
+        }
+    }
+
+    // Exact bit patterns, so one-ULP differences are visible
+    pub fn to_hex_string(&self) -> String {
+        format!(
+            "({:#010x}, {:#010x}, {:#010x})",
+            self.x.to_bits(),
+            self.y.to_bits(),
+            self.z.to_bits()
+        )
+    }
+
+    // e.g. format_with(",", false) gives CSV; Display stays "(x, y, z)"
+    pub fn format_with(&self, sep: &str, wrap: bool) -> String {
+        let body = format!("{}{}{}{}{}", self.x, sep, self.y, sep, self.z);
+        if wrap { format!("({})", body) } else { body }
+    }
+
+    // NaN components pass through unchanged
+    pub fn clamp_scalar(&self, min: f32, max: f32) -> Self {
+        Self {
+            x: self.x.clamp(min, max),
+            y: self.y.clamp(min, max),
+            z: self.z.clamp(min, max),
+        }
+    }
+
+    // NaN components are replaced with `nan` instead of passing through
+    pub fn clamp_scalar_nan(&self, min: f32, max: f32, nan: f32) -> Self {
+        let clamp = |c: f32| if c.is_nan() { nan } else { c.clamp(min, max) };
+        Self {
+            x: clamp(self.x),
+            y: clamp(self.y),
+            z: clamp(self.z),
+        }
+    }
+
+    pub fn replace_nan(&self, value: f32) -> Self {
+        let replace = |c: f32| if c.is_nan() { value } else { c };
+        Self {
+            x: replace(self.x),
+            y: replace(self.y),
+            z: replace(self.z),
+        }
+    }
+
+    // Both signs of infinity are replaced
+    pub fn replace_inf(&self, value: f32) -> Self {
+        let replace = |c: f32| if c.is_infinite() { value } else { c };
+        Self {
+            x: replace(self.x),
+            y: replace(self.y),
+            z: replace(self.z),
+        }
+    }
+
+    pub fn clamp_min(&self, min: f32) -> Self {
+        Self {
+            x: self.x.max(min),
+            y: self.y.max(min),
+            z: self.z.max(min),
+        }
+    }
+
+    pub fn clamp_max(&self, max: f32) -> Self {
+        Self {
+            x: self.x.min(max),
+            y: self.y.min(max),
+            z: self.z.min(max),
+        }
+    }
+
+    pub fn clamp_min_vec(&self, min: &Self) -> Self {
+        Self {
+            x: self.x.max(min.x),
+            y: self.y.max(min.y),
+            z: self.z.max(min.z),
+        }
+    }
+
+    pub fn clamp_max_vec(&self, max: &Self) -> Self {
+        Self {
+            x: self.x.min(max.x),
+            y: self.y.min(max.y),
+            z: self.z.min(max.z),
+        }
+    }
+
+    // Points outside the ball are pulled onto its surface along the same ray
+    pub fn clamp_to_sphere(&self, center: &Self, radius: f32) -> Self {
+        debug_assert!(radius >= 0.0, "Vect3::clamp_to_sphere radius is negative");
+        if self.distance(center) <= radius {
+            return *self;
+        }
+        *center + (*self - *center).normalize() * radius
+    }
+
+    // Zero cell components leave that axis unchanged
+    pub fn snap_to_grid(&self, cell: &Self) -> Self {
+        let snap = |c: f32, size: f32| {
+            if size == 0.0 {
+                c
+            } else {
+                (c / size).round() * size
+            }
+        };
+        Self {
+            x: snap(self.x, cell.x),
+            y: snap(self.y, cell.y),
+            z: snap(self.z, cell.z),
+        }
+    }
+
+    pub fn snap_to_grid_uniform(&self, size: f32) -> Self {
+        self.snap_to_grid(&Self::splat(size))
+    }
+
+    pub fn eq_mask(&self, other: &Self) -> (bool, bool, bool) {
+        (self.x == other.x, self.y == other.y, self.z == other.z)
+    }
+
+    pub fn approx_eq_mask(&self, other: &Self, eps: f32) -> (bool, bool, bool) {
+        (
+            (self.x - other.x).abs() <= eps,
+            (self.y - other.y).abs() <= eps,
+            (self.z - other.z).abs() <= eps,
+        )
+    }
+
+    pub fn select(mask: (bool, bool, bool), if_true: &Self, if_false: &Self) -> Self {
+        Self {
+            x: if mask.0 { if_true.x } else { if_false.x },
+            y: if mask.1 { if_true.y } else { if_false.y },
+            z: if mask.2 { if_true.z } else { if_false.z },
+        }
+    }
+
+    // Vertex-attribute packing: snorm maps [-1, 1] to [-127, 127]
+    pub fn to_snorm8(&self) -> [i8; 3] {
+        let pack = |c: f32| (c.clamp(-1.0, 1.0) * 127.0).round() as i8;
+        [pack(self.x), pack(self.y), pack(self.z)]
+    }
+
+    pub fn from_snorm8(v: [i8; 3]) -> Self {
+        // -128 and -127 both decode to -1.0
+        let unpack = |c: i8| (c as f32 / 127.0).max(-1.0);
+        Self::new(unpack(v[0]), unpack(v[1]), unpack(v[2]))
+    }
+
+    // unorm maps [0, 1] to [0, 255]
+    pub fn to_unorm8(&self) -> [u8; 3] {
+        let pack = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        [pack(self.x), pack(self.y), pack(self.z)]
+    }
+
+    pub fn from_unorm8(v: [u8; 3]) -> Self {
+        let unpack = |c: u8| c as f32 / 255.0;
+        Self::new(unpack(v[0]), unpack(v[1]), unpack(v[2]))
+    }
+
+    // 10-10-10-2: snorm x/y/z in bits 0..30 (x lowest), 2-bit alpha on top
+    pub fn to_rgb10a2(&self, a: u8) -> u32 {
+        debug_assert!(a < 4, "Vect3::to_rgb10a2 alpha does not fit in 2 bits");
+        let pack = |c: f32| ((c.clamp(-1.0, 1.0) * 511.0).round() as i32 as u32) & 0x3ff;
+        pack(self.x) | (pack(self.y) << 10) | (pack(self.z) << 20) | ((a as u32 & 0x3) << 30)
+    }
+
+    pub fn from_rgb10a2(packed: u32) -> (Self, u8) {
+        // Shift the field to the top and back down to sign-extend it;
+        // -512 and -511 both decode to -1.0
+        let unpack = |shift: u32| {
+            let c = ((packed >> shift) << 22) as i32 >> 22;
+            (c as f32 / 511.0).max(-1.0)
+        };
+        (
+            Self::new(unpack(0), unpack(10), unpack(20)),
+            (packed >> 30) as u8,
+        )
+    }
+
+    // Halves round away from zero
+    pub fn round(&self) -> Self {
+        Self {
+            x: self.x.round(),
+            y: self.y.round(),
+            z: self.z.round(),
+        }
+    }
+
+    // Halves round to the even neighbour (IEEE default)
+    pub fn round_ties_even(&self) -> Self {
+        Self {
+            x: self.x.round_ties_even(),
+            y: self.y.round_ties_even(),
+            z: self.z.round_ties_even(),
+        }
+    }
+
+    // `as` saturates at the i32 bounds and maps NaN to 0
+    pub fn floor_to_i32(&self) -> [i32; 3] {
+        [
+            self.x.floor() as i32,
+            self.y.floor() as i32,
+            self.z.floor() as i32,
+        ]
+    }
+
+    pub fn round_to_i32(&self) -> [i32; 3] {
+        [
+            self.x.round() as i32,
+            self.y.round() as i32,
+            self.z.round() as i32,
+        ]
+    }
+
+    pub fn exp2(&self) -> Self {
+        let result = Self {
+            x: self.x.exp2(),
+            y: self.y.exp2(),
+            z: self.z.exp2(),
+        };
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::exp2 produced non-finite result"
+        );
+        result
+    }
+
+    pub fn log2(&self) -> Self {
+        let result = Self {
+            x: self.x.log2(),
+            y: self.y.log2(),
+            z: self.z.log2(),
+        };
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::log2 produced non-finite result"
+        );
+        result
+    }
+
+    // GLSL semantics: 0.0 where x < edge, otherwise 1.0
+    pub fn step(edge: &Vect3, x: &Vect3) -> Vect3 {
+        let step = |e: f32, v: f32| if v < e { 0.0 } else { 1.0 };
+        Vect3::new(step(edge.x, x.x), step(edge.y, x.y), step(edge.z, x.z))
+    }
+
+    pub fn smoothstep(edge0: &Vect3, edge1: &Vect3, x: &Vect3) -> Vect3 {
+        let smooth = |e0: f32, e1: f32, v: f32| {
+            let t = ((v - e0) / (e1 - e0)).clamp(0.0, 1.0);
+            t * t * (3.0 - 2.0 * t)
+        };
+        let result = Vect3::new(
+            smooth(edge0.x, edge1.x, x.x),
+            smooth(edge0.y, edge1.y, x.y),
+            smooth(edge0.z, edge1.z, x.z),
+        );
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::smoothstep produced non-finite result"
+        );
+        result
+    }
+
+    pub fn abs_diff(&self, other: &Self) -> Self {
+        Self {
+            x: (self.x - other.x).abs(),
+            y: (self.y - other.y).abs(),
+            z: (self.z - other.z).abs(),
+        }
+    }
+
+    // Per component, whichever value has the larger magnitude, sign kept
+    pub fn max_abs(&self, other: &Self) -> Self {
+        let pick = |a: f32, b: f32| if a.abs() >= b.abs() { a } else { b };
+        Self {
+            x: pick(self.x, other.x),
+            y: pick(self.y, other.y),
+            z: pick(self.z, other.z),
+        }
+    }
+
+    // Schlick's approximation per RGB channel: f0 + (1 - f0)(1 - cos)^5
+    pub fn fresnel_schlick(cos_theta: f32, f0: &Vect3) -> Vect3 {
+        let k = (1.0 - cos_theta.clamp(0.0, 1.0)).powi(5);
+        let result = *f0 + (Vect3::splat(1.0) - *f0) * k;
+        debug_assert!(
+            result.x.is_finite() && result.y.is_finite() && result.z.is_finite(),
+            "Vect3::fresnel_schlick produced non-finite result"
+        );
+        result
+    }
+
+    pub fn flip(&self, flip_x: bool, flip_y: bool, flip_z: bool) -> Self {
+        Self {
+            x: if flip_x { -self.x } else { self.x },
+            y: if flip_y { -self.y } else { self.y },
+            z: if flip_z { -self.z } else { self.z },
+        }
     }
 }
 
@@ -217,6 +1368,18 @@ impl Sub for Vect3 {
         }
     }
 }
+impl Add<&Vect3> for Vect3 {
+    type Output = Self;
+    fn add(self, rhs: &Vect3) -> Self::Output {
+        self + *rhs
+    }
+}
+impl Sub<&Vect3> for Vect3 {
+    type Output = Self;
+    fn sub(self, rhs: &Vect3) -> Self::Output {
+        self - *rhs
+    }
+}
 impl Mul<f32> for Vect3 {
     type Output = Self;
     fn mul(self, rhs: f32) -> Self::Output {
@@ -262,6 +1425,16 @@ impl SubAssign for Vect3 {
         self.z -= rhs.z;
     }
 }
+impl AddAssign<&Vect3> for Vect3 {
+    fn add_assign(&mut self, rhs: &Vect3) {
+        *self += *rhs;
+    }
+}
+impl SubAssign<&Vect3> for Vect3 {
+    fn sub_assign(&mut self, rhs: &Vect3) {
+        *self -= *rhs;
+    }
+}
 impl MulAssign<f32> for Vect3 {
     fn mul_assign(&mut self, rhs: f32) {
         self.x *= rhs;
@@ -277,6 +1450,26 @@ impl DivAssign<f32> for Vect3 {
     }
 }
 
+// Componentwise, for non-uniform scaling in place
+impl MulAssign<Vect3> for Vect3 {
+    fn mul_assign(&mut self, rhs: Vect3) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+        self.z *= rhs.z;
+    }
+}
+impl DivAssign<Vect3> for Vect3 {
+    fn div_assign(&mut self, rhs: Vect3) {
+        debug_assert!(
+            rhs.x != 0.0 && rhs.y != 0.0 && rhs.z != 0.0,
+            "Vect3 division by a zero component"
+        );
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
+    }
+}
+
 // Indexing
 impl Index<usize> for Vect3 {
     type Output = f32;
@@ -299,14 +1492,20 @@ impl IndexMut<usize> for Vect3 {
         }
     }
 }
+impl Index<Range<usize>> for Vect3 {
+    type Output = [f32];
+    fn index(&self, r: Range<usize>) -> &Self::Output {
+        &self.as_slice()[r]
+    }
+}
 
 // From conversions
-impl From<[f32; 3]> for Vect3 {
-    fn from(arr: [f32; 3]) -> Self {
+impl<T: Component> From<[T; 3]> for Vect3 {
+    fn from(arr: [T; 3]) -> Self {
         Self {
-            x: arr[0],
-            y: arr[1],
-            z: arr[2],
+            x: arr[0].to_f32(),
+            y: arr[1].to_f32(),
+            z: arr[2].to_f32(),
         }
     }
 }
@@ -319,15 +1518,6 @@ impl From<(f32, f32, f32)> for Vect3 {
         }
     }
 }
-impl From<[i32; 3]> for Vect3 {
-    fn from(arr: [i32; 3]) -> Self {
-        Self {
-            x: arr[0] as f32,
-            y: arr[1] as f32,
-            z: arr[2] as f32,
-        }
-    }
-}
 impl From<(i32, i32, i32)> for Vect3 {
     fn from(t: (i32, i32, i32)) -> Self {
         Self {
@@ -342,6 +1532,31 @@ impl From<Vect3> for [f32; 3] {
         [v.x, v.y, v.z]
     }
 }
+impl From<Vect3> for [f64; 3] {
+    fn from(v: Vect3) -> Self {
+        [v.x as f64, v.y as f64, v.z as f64]
+    }
+}
+impl From<Vect3> for (f64, f64, f64) {
+    fn from(v: Vect3) -> Self {
+        (v.x as f64, v.y as f64, v.z as f64)
+    }
+}
+// 2D lifts onto the z = 0 plane; 3D drops z
+impl From<Vect2> for Vect3 {
+    fn from(v: Vect2) -> Self {
+        Self {
+            x: v.x,
+            y: v.y,
+            z: 0.0,
+        }
+    }
+}
+impl From<Vect3> for Vect2 {
+    fn from(v: Vect3) -> Self {
+        Vect2::new(v.x, v.y)
+    }
+}
 
 // TryFrom slices
 impl TryFrom<&[f32]> for Vect3 {
@@ -380,6 +1595,22 @@ impl Display for Vect3 {
     }
 }
 
+// Prints a whole slice as "[(x, y, z), (x, y, z)]" for logging meshes
+pub struct DisplaySlice<'a>(pub &'a [Vect3]);
+
+impl Display for DisplaySlice<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "[")?;
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", v)?;
+        }
+        write!(f, "]")
+    }
+}
+
 // Hash
 impl Hash for Vect3 {
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -388,3 +1619,67 @@ impl Hash for Vect3 {
         state.write_u32(self.z.to_bits());
     }
 }
+
+// Total-order wrapper so vectors can be used as map keys
+#[derive(Debug, Clone, Copy)]
+pub struct OrdVect3(pub Vect3);
+
+impl OrdVect3 {
+    // Collapse -0.0 onto 0.0 and every NaN payload onto one NaN
+    fn canonical(&self) -> [f32; 3] {
+        let canon = |c: f32| {
+            if c.is_nan() {
+                f32::NAN
+            } else if c == 0.0 {
+                0.0
+            } else {
+                c
+            }
+        };
+        [canon(self.0.x), canon(self.0.y), canon(self.0.z)]
+    }
+}
+
+impl PartialEq for OrdVect3 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrdVect3 {}
+
+impl PartialOrd for OrdVect3 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrdVect3 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let a = self.canonical();
+        let b = other.canonical();
+        a[0].total_cmp(&b[0])
+            .then(a[1].total_cmp(&b[1]))
+            .then(a[2].total_cmp(&b[2]))
+    }
+}
+
+impl Hash for OrdVect3 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for c in self.canonical() {
+            state.write_u32(c.to_bits());
+        }
+    }
+}
+
+impl From<Vect3> for OrdVect3 {
+    fn from(v: Vect3) -> Self {
+        Self(v)
+    }
+}
+
+impl From<OrdVect3> for Vect3 {
+    fn from(v: OrdVect3) -> Self {
+        v.0
+    }
+}
@@ -0,0 +1,67 @@
+use crate::lerp::VectorLerp;
+use crate::vect2::Vect2;
+use crate::vect3::Vect3;
+use std::ops::{Add, Div, Mul, Sub};
+
+// Surface shared by Vect2 and Vect3, so geometry helpers can be written
+// once; the inherent methods stay and these impls just forward to them.
+// Interpolation comes from VectorLerp, so crate::lerp accepts any Vector
+pub trait Vector:
+    VectorLerp
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<f32, Output = Self>
+    + Div<f32, Output = Self>
+{
+    const ZERO: Self;
+
+    fn splat(v: f32) -> Self;
+    fn dot(&self, other: &Self) -> f32;
+    fn length(&self) -> f32;
+    fn normalize(&self) -> Self;
+}
+
+impl Vector for Vect2 {
+    const ZERO: Self = Vect2 { x: 0.0, y: 0.0 };
+
+    fn splat(v: f32) -> Self {
+        Vect2::splat(v)
+    }
+
+    fn dot(&self, other: &Self) -> f32 {
+        Vect2::dot(self, other)
+    }
+
+    fn length(&self) -> f32 {
+        Vect2::length(self)
+    }
+
+    fn normalize(&self) -> Self {
+        Vect2::normalize(self)
+    }
+}
+
+impl Vector for Vect3 {
+    const ZERO: Self = Vect3 {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    fn splat(v: f32) -> Self {
+        Vect3::splat(v)
+    }
+
+    fn dot(&self, other: &Self) -> f32 {
+        Vect3::dot(self, other)
+    }
+
+    fn length(&self) -> f32 {
+        Vect3::length(self)
+    }
+
+    fn normalize(&self) -> Self {
+        Vect3::normalize(self)
+    }
+}
@@ -0,0 +1,38 @@
+use tiny_vect::{Vect2, Vect3, VectorLerp, lerp};
+
+// One keyframe routine for every interpolatable type
+fn sample<V: VectorLerp>(keys: &[(f32, V)], time: f32) -> V {
+    let i = keys
+        .windows(2)
+        .position(|w| time <= w[1].0)
+        .unwrap_or(keys.len() - 2);
+    let (t0, a) = keys[i];
+    let (t1, b) = keys[i + 1];
+    lerp(a, b, (time - t0) / (t1 - t0))
+}
+
+#[test]
+fn test_lerp_scalar_and_vectors() {
+    assert_eq!(lerp(2.0f32, 4.0, 0.5), 3.0);
+    assert_eq!(
+        lerp(Vect2::new(0.0, 0.0), Vect2::new(2.0, 4.0), 0.5),
+        Vect2::new(1.0, 2.0)
+    );
+    assert_eq!(
+        lerp(Vect3::default(), Vect3::new(2.0, 4.0, 6.0), 0.25),
+        Vect3::new(0.5, 1.0, 1.5)
+    );
+}
+
+#[test]
+fn test_generic_keyframes() {
+    let scalar_keys = [(0.0, 0.0f32), (1.0, 10.0), (3.0, 20.0)];
+    assert_eq!(sample(&scalar_keys, 2.0), 15.0);
+
+    let vec_keys = [
+        (0.0, Vect3::default()),
+        (1.0, Vect3::new(10.0, 0.0, 0.0)),
+        (3.0, Vect3::new(10.0, 20.0, 0.0)),
+    ];
+    assert_eq!(sample(&vec_keys, 2.0), Vect3::new(10.0, 10.0, 0.0));
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "nalgebra")]
+
+use nalgebra::{Point2, Point3, Vector2, Vector3};
+use tiny_vect::{Vect2, Vect3};
+
+#[test]
+fn test_vect3_vector_round_trip() {
+    let v = Vect3::new(1.0, -2.0, 3.5);
+    let na: Vector3<f32> = v.into();
+    assert_eq!(na, Vector3::new(1.0, -2.0, 3.5));
+    assert_eq!(Vect3::from(na), v);
+}
+
+#[test]
+fn test_vect3_point_round_trip() {
+    let v = Vect3::new(4.0, 5.0, 6.0);
+    let p: Point3<f32> = v.into();
+    assert_eq!(p, Point3::new(4.0, 5.0, 6.0));
+    assert_eq!(Vect3::from(p), v);
+    // Point minus point is a vector in nalgebra
+    let d: Vect3 = (p - Point3::origin()).into();
+    assert_eq!(d, v);
+}
+
+#[test]
+fn test_vect2_round_trips() {
+    let v = Vect2::new(1.5, -0.5);
+    let na: Vector2<f32> = v.into();
+    let p: Point2<f32> = v.into();
+    assert_eq!(Vect2::from(na), v);
+    assert_eq!(Vect2::from(p), v);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use tiny_vect::Vect3;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "tiny_vect::serde_tuple")]
+    position: Vect3,
+}
+
+#[test]
+fn test_tuple_round_trip_ron() {
+    let cfg = Config {
+        position: Vect3::new(1.0, 2.5, -3.0),
+    };
+    let text = ron::to_string(&cfg).unwrap();
+    assert_eq!(text, "(position:(1.0,2.5,-3.0))");
+    let back: Config = ron::from_str(&text).unwrap();
+    assert_eq!(back, cfg);
+}
+
+#[test]
+fn test_tuple_differs_from_seq() {
+    let v = Vect3::new(1.0, 2.0, 3.0);
+    let seq = ron::to_string(&[v.x, v.y, v.z][..]).unwrap();
+    let tuple = ron::to_string(&Config { position: v }).unwrap();
+    assert_eq!(seq, "[1.0,2.0,3.0]");
+    assert!(tuple.contains("(1.0,2.0,3.0)"));
+}
+
+#[test]
+fn test_default_struct_form() {
+    let v = Vect3::new(1.0, 2.0, 3.0);
+    let text = ron::to_string(&v).unwrap();
+    assert_eq!(text, "(x:1.0,y:2.0,z:3.0)");
+    assert_eq!(ron::from_str::<Vect3>(&text).unwrap(), v);
+}
+
+#[derive(Serialize, Deserialize)]
+struct Rounded {
+    #[serde(with = "tiny_vect::serde_rounded")]
+    default: Vect3,
+    #[serde(
+        serialize_with = "tiny_vect::serde_rounded::serialize_decimals::<3, _>",
+        deserialize_with = "tiny_vect::serde_rounded::deserialize"
+    )]
+    three: Vect3,
+}
+
+#[test]
+fn test_rounded_hides_jitter() {
+    let a = Vect3::new(0.1 + 0.2, 1.0 / 3.0, -2.5);
+    let b = Vect3::new(0.3, 0.33331, -2.500_02);
+    let ra = ron::to_string(&Rounded {
+        default: a,
+        three: a,
+    })
+    .unwrap();
+    let rb = ron::to_string(&Rounded {
+        default: b,
+        three: b,
+    })
+    .unwrap();
+    assert_eq!(
+        ra,
+        "(default:(x:0.3,y:0.3333,z:-2.5),three:(x:0.3,y:0.333,z:-2.5))"
+    );
+    assert_eq!(ra, rb);
+}
+
+#[test]
+fn test_rounded_deserialize_unchanged() {
+    let text = "(default:(x:0.12345,y:1.0,z:2.0),three:(x:0.98765,y:0.0,z:0.0))";
+    let r: Rounded = ron::from_str(text).unwrap();
+    assert_eq!(r.default.x, 0.12345);
+    assert_eq!(r.three.x, 0.98765);
+}
@@ -1,6 +1,4 @@
-use std::f32::INFINITY;
-
-use tiny_vect::Vect2;
+use tiny_vect::{AreaAccumulator, Polar, Vect2};
 
 #[test]
 fn test_cross_product() {
@@ -9,13 +7,58 @@ fn test_cross_product() {
     assert_eq!(vector1.cross(&vector2), 1.0);
 }
 
+#[test]
+fn test_parallelogram_area() {
+    let a = Vect2::new(2.0, 0.0);
+    let b = Vect2::new(1.0, 3.0);
+    assert_eq!(Vect2::parallelogram_area(&a, &b), 6.0);
+    assert_eq!(Vect2::parallelogram_area(&b, &a), 6.0);
+    assert_eq!(Vect2::parallelogram_area(&a, &(a * 2.0)), 0.0);
+}
+
+#[test]
+fn test_are_collinear() {
+    let a = Vect2::new(0.0, 0.0);
+    let b = Vect2::new(1.0, 1.0);
+    assert!(Vect2::are_collinear(&a, &b, &Vect2::new(-3.0, -3.0), 1e-6));
+    assert!(!Vect2::are_collinear(&a, &b, &Vect2::new(1.0, -1.0), 1e-6));
+    assert!(Vect2::are_collinear(&a, &b, &Vect2::new(2.0, 2.001), 1e-2));
+}
+
+#[test]
+fn test_segments_bbox_overlap() {
+    let a1 = Vect2::new(0.0, 0.0);
+    let a2 = Vect2::new(2.0, 2.0);
+    // Crossing segments
+    assert!(Vect2::segments_bbox_overlap(
+        &a1,
+        &a2,
+        &Vect2::new(0.0, 2.0),
+        &Vect2::new(2.0, 0.0)
+    ));
+    // Clearly separated
+    assert!(!Vect2::segments_bbox_overlap(
+        &a1,
+        &a2,
+        &Vect2::new(5.0, 5.0),
+        &Vect2::new(7.0, 6.0)
+    ));
+    // Shared endpoint counts as overlap
+    assert!(Vect2::segments_bbox_overlap(
+        &a1,
+        &a2,
+        &a2,
+        &Vect2::new(4.0, 2.0)
+    ));
+}
+
 #[test]
 #[should_panic(expected = "Vect2::cross produced NaN or infinity")]
 fn test_cross_product_max() {
     let vector1 = Vect2::new(f32::MAX, f32::MAX);
     let vector2 = Vect2::new(f32::MAX, f32::MAX);
     let result = vector1.cross(&vector2);
-    assert_eq!(result, INFINITY);
+    assert_eq!(result, f32::INFINITY);
 }
 
 #[test]
@@ -25,13 +68,20 @@ fn test_dot_product() {
     assert_eq!(vector1.dot(&vector2), 0.0);
 }
 
+#[test]
+fn test_dot_f64() {
+    let a = Vect2::new(1e8, 1.0);
+    let b = Vect2::new(1.0, 1.0);
+    assert_eq!(a.dot_f64(&b), 100_000_001.0);
+}
+
 #[test]
 #[should_panic(expected = "Vect2::dot produced NaN or infinity")]
 fn test_dot_product_max() {
     let vector1 = Vect2::new(f32::MAX, f32::MAX);
     let vector2 = Vect2::new(f32::MAX, f32::MAX);
     let result = vector1.dot(&vector2);
-    assert_eq!(result, INFINITY);
+    assert_eq!(result, f32::INFINITY);
 }
 
 #[test]
@@ -48,6 +98,12 @@ fn test_length_max() {
     assert_eq!(result, f32::INFINITY);
 }
 
+#[test]
+fn test_length_f64() {
+    assert_eq!(Vect2::new(3.0, 4.0).length_f64(), 5.0);
+    assert!(Vect2::new(f32::MAX, f32::MAX).length_f64().is_finite());
+}
+
 #[test]
 fn test_normalize() {
     let vector = Vect2::new(1.0, 0.0);
@@ -55,6 +111,36 @@ fn test_normalize() {
     assert_eq!(normalized.length(), 1.0);
 }
 
+#[test]
+fn test_normalize_or_zero() {
+    assert!(Vect2::default().normalize_or_zero().is_zero());
+    assert!(Vect2::new(f32::INFINITY, 0.0).normalize_or_zero().is_zero());
+    assert!(Vect2::new(f32::NAN, 1.0).normalize_or_zero().is_zero());
+    assert_eq!(
+        Vect2::new(3e19, 4e19).normalize_or_zero(),
+        Vect2::new(0.6, 0.8)
+    );
+    assert!((Vect2::splat(f32::MAX).normalize_or_zero().length() - 1.0).abs() < 1e-6);
+    assert_eq!(
+        Vect2::new(0.0, -2.0).normalize_or_zero(),
+        Vect2::new(0.0, -1.0)
+    );
+}
+
+#[test]
+fn test_direction_or() {
+    let right = Vect2::new(1.0, 0.0);
+    assert_eq!(Vect2::default().direction_or(right), right);
+    assert_eq!(
+        Vect2::new(0.0, 3.0).direction_or(right),
+        Vect2::new(0.0, 1.0)
+    );
+    assert_eq!(
+        Vect2::new(0.0, -3e30).direction_or(right),
+        Vect2::new(0.0, -1.0)
+    );
+}
+
 #[test]
 #[should_panic(expected = "Vect2::normalize produced non-finite result")]
 fn test_normalize_max() {
@@ -75,8 +161,8 @@ fn test_max_add() {
     let vector1 = Vect2::new(f32::MAX, f32::MAX);
     let vector2 = Vect2::new(f32::MAX, f32::MAX);
     let result = vector1 + vector2;
-    assert_eq!(result.x, INFINITY);
-    assert_eq!(result.y, INFINITY);
+    assert_eq!(result.x, f32::INFINITY);
+    assert_eq!(result.y, f32::INFINITY);
 }
 
 #[test]
@@ -113,6 +199,12 @@ fn test_from_array_i32() {
     assert_eq!(v, Vect2::new(5.0, 6.0));
 }
 
+#[test]
+fn test_from_array_u8() {
+    let v: Vect2 = [200u8, 7].into();
+    assert_eq!(v, Vect2::new(200.0, 7.0));
+}
+
 #[test]
 fn test_from_tuple_i32() {
     let v: Vect2 = (7, 8).into();
@@ -207,3 +299,425 @@ fn test_from_slice_f32_cross() {
     let base: Vect2 = (&[1.0, 2.0][..]).try_into().unwrap();
     let _ = base.cross(&Vect2::new(1.0, 1.0));
 }
+
+#[test]
+fn test_is_parallel_long_vectors() {
+    let a = Vect2::new(1234.5, 6789.1);
+    let b = a * 3.7;
+    assert!(a.is_parallel(&b));
+    assert!(a.is_parallel(&-b));
+}
+
+#[test]
+fn test_is_parallel_tol() {
+    let a = Vect2::new(1000.0, 0.0);
+    let b = Vect2::new(1000.0, 1.0);
+    assert!(!a.is_parallel(&b));
+    assert!(a.is_parallel_tol(&b, 1e-2));
+    assert!(!a.is_parallel_tol(&b, 1e-4));
+}
+
+#[test]
+fn test_is_parallel_tiny_and_huge() {
+    let tiny_x = Vect2::new(1e-25, 0.0);
+    assert!(!tiny_x.is_parallel(&Vect2::new(0.0, 1e-25)));
+    assert!(tiny_x.is_parallel(&Vect2::new(-3e-25, 0.0)));
+    assert!(!tiny_x.is_parallel_tol(&Vect2::new(0.0, 1e-25), 1e-3));
+
+    let huge = Vect2::new(1e20, 2e20);
+    assert!(huge.is_parallel(&(huge * 3.0)));
+    assert!(!huge.is_parallel(&Vect2::new(-2e20, 1e20)));
+    assert!(huge.is_parallel_tol(&-huge, 1e-6));
+    assert!(!huge.is_parallel_tol(&Vect2::new(-2e20, 1e20), 1e-3));
+}
+
+#[test]
+fn test_is_normalized_after_normalize() {
+    assert!(Vect2::new(1.0, 3.0).normalize().is_normalized());
+    assert!(Vect2::new(1.001, 0.0).is_normalized_tol(1e-2));
+}
+
+#[test]
+fn test_saturating_add() {
+    let m = Vect2::splat(f32::MAX);
+    assert_eq!(m.saturating_add(m), Vect2::splat(f32::MAX));
+    assert_eq!(m.saturating_mul(-2.0), Vect2::splat(f32::MIN));
+}
+
+#[test]
+fn test_iter_mut() {
+    let mut v = Vect2::new(1.5, -0.5);
+    for c in v.iter_mut() {
+        *c = c.clamp(0.0, 1.0);
+    }
+    assert_eq!(v, Vect2::new(1.0, 0.0));
+}
+
+#[test]
+fn test_component_reductions() {
+    let v = Vect2::new(-2.0, 3.0);
+    assert_eq!(v.sum_elements(), 1.0);
+    assert_eq!(v.product_elements(), -6.0);
+    assert_eq!(v.min_component(), -2.0);
+    assert_eq!(v.max_component(), 3.0);
+}
+
+#[test]
+fn test_reflect_known() {
+    let v = Vect2::new(1.0, -1.0);
+    assert_eq!(v.reflect(&Vect2::new(0.0, 2.0)), Vect2::new(1.0, 1.0));
+    let r = Vect2::new(3.0, -4.0).reflect(&Vect2::new(1.0, 1.0));
+    assert!((r - Vect2::new(4.0, -3.0)).length() < 1e-5);
+}
+
+#[test]
+fn test_reflect_with_cos() {
+    let v = Vect2::new(1.0, -1.0);
+    let (r, cos) = v.reflect_with_cos(&Vect2::new(0.0, 2.0));
+    assert_eq!(r, Vect2::new(1.0, 1.0));
+    assert!((cos - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-6);
+    let (r, cos) = Vect2::new(3.0, -4.0).reflect_with_cos(&Vect2::new(1.0, 1.0));
+    assert!((r - Vect2::new(3.0, -4.0).reflect(&Vect2::new(1.0, 1.0))).length() < 1e-6);
+    assert!((cos - 0.2 * std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-6);
+    assert_eq!(
+        Vect2::default().reflect_with_cos(&Vect2::new(0.0, 1.0)).1,
+        0.0
+    );
+}
+
+#[test]
+fn test_reflect_across_line() {
+    let p = Vect2::new(0.0, 1.0);
+    let r = p.reflect_across_line(&Vect2::default(), &Vect2::new(1.0, 0.0));
+    assert_eq!(r, Vect2::new(0.0, -1.0));
+
+    // Line y = x + 1
+    let r = Vect2::new(1.0, 0.0).reflect_across_line(&Vect2::new(0.0, 1.0), &Vect2::new(2.0, 2.0));
+    assert!((r - Vect2::new(-1.0, 2.0)).length() < 1e-6);
+}
+
+#[test]
+#[should_panic(expected = "direction is zero")]
+fn test_reflect_across_line_zero_direction() {
+    let _ = Vect2::new(1.0, 1.0).reflect_across_line(&Vect2::default(), &Vect2::default());
+}
+
+#[test]
+fn test_refract_straight_through() {
+    let v = Vect2::new(0.0, -1.0);
+    let r = v.refract(&Vect2::new(0.0, 1.0), 1.5).unwrap();
+    assert!((r - Vect2::new(0.0, -1.0)).length() < 1e-6);
+}
+
+#[test]
+fn test_refract_snell() {
+    let eta = 1.0 / 1.5;
+    let v = Vect2::new(1.0, -1.0);
+    let r = v.refract(&Vect2::new(0.0, 1.0), eta).unwrap();
+    let sin_i = std::f32::consts::FRAC_1_SQRT_2;
+    assert!((r.x - sin_i * eta).abs() < 1e-6);
+    assert!(r.y < 0.0);
+    assert!(r.is_normalized());
+}
+
+#[test]
+fn test_refract_total_internal_reflection() {
+    let v = Vect2::new(1.0, -0.2);
+    assert!(v.refract(&Vect2::new(0.0, 1.0), 1.5).is_none());
+}
+
+#[test]
+fn test_lerp_angle_short_path() {
+    let mid = Vect2::lerp_angle(3.0, -3.0, 0.5);
+    assert!((mid.abs() - std::f32::consts::PI).abs() < 1e-5);
+    assert!((Vect2::lerp_angle(0.0, 1.0, 0.25) - 0.25).abs() < 1e-6);
+}
+
+#[test]
+fn test_lerp_angle_wraps_across_seam() {
+    assert!((Vect2::lerp_angle(3.0, -3.0, 1.0) + 3.0).abs() < 1e-6);
+    assert!((Vect2::lerp_angle(-3.0, 3.0, 1.0) - 3.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_lerp_angle_tiny_delta() {
+    assert_eq!(Vect2::lerp_angle(0.0, 1e-6, 0.5), 5e-7);
+    assert_eq!(Vect2::lerp_angle(0.0, -1e-6, 1.0), -1e-6);
+}
+
+#[test]
+fn test_wrap_angle() {
+    use std::f32::consts::PI;
+    assert!((Vect2::wrap_angle(3.0 * PI) - PI).abs() < 1e-5);
+    assert!((Vect2::wrap_angle(-3.0 * PI) - PI).abs() < 1e-5);
+    assert_eq!(Vect2::wrap_angle(PI), PI);
+    assert!((Vect2::wrap_angle(-PI) - PI).abs() < 1e-6);
+    assert!((Vect2::wrap_angle(0.5 + 4.0 * PI) - 0.5).abs() < 1e-5);
+}
+
+#[test]
+fn test_wrap_angle_in_range_identity() {
+    use std::f32::consts::PI;
+    for a in [0.0, 1e-8, -1e-8, 0.1, -2.5, 3.0, PI - 1e-6, -PI + 1e-6] {
+        assert_eq!(Vect2::wrap_angle(a), a);
+    }
+}
+
+#[test]
+fn test_wrap_angle_positive() {
+    use std::f32::consts::{PI, TAU};
+    assert!((Vect2::wrap_angle_positive(-PI / 2.0) - 1.5 * PI).abs() < 1e-5);
+    assert_eq!(Vect2::wrap_angle_positive(TAU), 0.0);
+    assert_eq!(Vect2::wrap_angle_positive(-1e-10), 0.0);
+}
+
+#[test]
+fn test_turn_angle() {
+    use std::f32::consts::FRAC_PI_2;
+    let a = Vect2::new(0.0, 0.0);
+    let corner = Vect2::new(2.0, 0.0);
+    let left = Vect2::new(2.0, 3.0);
+    let right = Vect2::new(2.0, -3.0);
+    assert!((Vect2::turn_angle(&a, &corner, &left) - FRAC_PI_2).abs() < 1e-6);
+    assert!((Vect2::turn_angle(&a, &corner, &right) + FRAC_PI_2).abs() < 1e-6);
+    assert_eq!(Vect2::turn_angle(&a, &corner, &Vect2::new(5.0, 0.0)), 0.0);
+}
+
+#[test]
+fn test_turn_angle_reversal() {
+    use std::f32::consts::PI;
+    let a = Vect2::new(0.0, 0.0);
+    let b = Vect2::new(2.0, 0.0);
+    assert_eq!(Vect2::turn_angle(&a, &b, &a).abs(), PI);
+    assert_eq!(Vect2::turn_angle(&a, &b, &Vect2::new(1.0, 0.0)).abs(), PI);
+}
+
+#[test]
+fn test_slerp() {
+    let a = Vect2::new(1.0, 0.0);
+    let b = Vect2::new(0.0, 1.0);
+    let mid = a.slerp(&b, 0.5);
+    let h = std::f32::consts::FRAC_1_SQRT_2;
+    assert!((mid - Vect2::new(h, h)).length() < 1e-6);
+    assert!((a.slerp(&b, 1.0) - b).length() < 1e-6);
+}
+
+#[test]
+fn test_to_hex_string() {
+    let a = Vect2::new(1.0, 0.5);
+    let b = Vect2::new(1.0, f32::from_bits(0.5f32.to_bits() + 1));
+    assert_eq!(a.to_hex_string(), "(0x3f800000, 0x3f000000)");
+    assert_ne!(a.to_hex_string(), b.to_hex_string());
+}
+
+#[test]
+fn test_clamp_scalar_nan() {
+    let v = Vect2::new(f32::NAN, 3.0);
+    assert!(v.clamp_scalar(0.0, 1.0).x.is_nan());
+    assert_eq!(v.clamp_scalar_nan(0.0, 1.0, 0.5), Vect2::new(0.5, 1.0));
+}
+
+#[test]
+fn test_replace_nan_inf() {
+    let v = Vect2::new(f32::NAN, f32::NEG_INFINITY);
+    let n = v.replace_nan(0.0);
+    assert_eq!(n.x, 0.0);
+    assert_eq!(n.y, f32::NEG_INFINITY);
+    let i = v.replace_inf(1.0);
+    assert!(i.x.is_nan());
+    assert_eq!(i.y, 1.0);
+}
+
+#[test]
+fn test_snap_to_grid() {
+    assert_eq!(
+        Vect2::new(3.4, -1.2).snap_to_grid(&Vect2::new(2.0, 0.0)),
+        Vect2::new(4.0, -1.2)
+    );
+    assert_eq!(
+        Vect2::new(0.26, 0.74).snap_to_grid_uniform(0.5),
+        Vect2::new(0.5, 0.5)
+    );
+}
+
+#[test]
+fn test_eq_masks() {
+    let a = Vect2::new(1.0, 2.0);
+    let b = Vect2::new(1.05, 2.0);
+    assert_eq!(a.eq_mask(&b), (false, true));
+    assert_eq!(a.approx_eq_mask(&b, 0.1), (true, true));
+}
+
+#[test]
+fn test_polar_tuple() {
+    let (r, a) = Vect2::new(0.0, 2.0).to_polar();
+    assert_eq!(r, 2.0);
+    assert!((a - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    let v = Vect2::from_polar(2.0, std::f32::consts::PI);
+    assert!((v - Vect2::new(-2.0, 0.0)).length() < 1e-6);
+}
+
+#[test]
+fn test_to_polar_batch() {
+    use std::f32::consts::{FRAC_PI_2, PI};
+    let polar = Vect2::to_polar_batch(&[
+        Vect2::new(3.0, 0.0),
+        Vect2::new(0.0, 2.0),
+        Vect2::new(-1.0, 0.0),
+        Vect2::new(0.0, -4.0),
+    ]);
+    let expected = [(3.0, 0.0), (2.0, FRAC_PI_2), (1.0, PI), (4.0, -FRAC_PI_2)];
+    assert_eq!(polar.len(), expected.len());
+    for ((r, a), (er, ea)) in polar.into_iter().zip(expected) {
+        assert_eq!(r, er);
+        assert!((a - ea).abs() < 1e-6);
+    }
+    assert!(Vect2::to_polar_batch(&[]).is_empty());
+}
+
+#[test]
+fn test_polar_struct_round_trip() {
+    let v = Vect2::new(-3.0, 4.0);
+    let p = Polar::from(v);
+    assert!((p.radius - 5.0).abs() < 1e-6);
+    let back: Vect2 = p.into();
+    assert!((back - v).length() < 1e-5);
+}
+
+#[test]
+fn test_lerp_vec() {
+    let a = Vect2::new(0.0, 0.0);
+    let b = Vect2::new(4.0, 8.0);
+    assert_eq!(a.lerp_vec(&b, &Vect2::new(0.25, 1.0)), Vect2::new(1.0, 8.0));
+}
+
+#[test]
+fn test_is_approx_zero() {
+    assert!(Vect2::new(0.0, -1e-7).is_approx_zero());
+    assert!(!Vect2::new(0.0, 0.1).is_approx_zero());
+    assert!(Vect2::new(0.3, 0.4).is_near_zero(0.51));
+}
+
+#[test]
+fn test_select() {
+    let r = Vect2::select((false, true), &Vect2::splat(1.0), &Vect2::splat(2.0));
+    assert_eq!(r, Vect2::new(2.0, 1.0));
+}
+
+#[test]
+fn test_round_ties_even() {
+    let v = Vect2::new(-0.5, 3.5);
+    assert_eq!(v.round(), Vect2::new(-1.0, 4.0));
+    assert_eq!(v.round_ties_even(), Vect2::new(-0.0, 4.0));
+}
+
+#[test]
+fn test_floor_and_round_to_i32() {
+    let v = Vect2::new(-1.5, 7.49);
+    assert_eq!(v.floor_to_i32(), [-2, 7]);
+    assert_eq!(v.round_to_i32(), [-2, 7]);
+}
+
+#[test]
+fn test_exp2_log2() {
+    assert_eq!(Vect2::new(-1.0, 4.0).exp2(), Vect2::new(0.5, 16.0));
+    assert_eq!(Vect2::new(1024.0, 0.25).log2(), Vect2::new(10.0, -2.0));
+}
+
+#[test]
+fn test_from_fn() {
+    assert_eq!(Vect2::from_fn(|i| i as f32 + 1.0), Vect2::new(1.0, 2.0));
+}
+
+#[test]
+fn test_format_with() {
+    let v = Vect2::new(1.5, -2.0);
+    assert_eq!(v.format_with(",", false), "1.5,-2");
+    assert_eq!(v.format_with(", ", true), v.to_string());
+}
+
+#[test]
+fn test_clamp_min_max() {
+    let v = Vect2::new(-1.0, 5.0);
+    assert_eq!(v.clamp_min(0.0), Vect2::new(0.0, 5.0));
+    assert_eq!(v.clamp_max(2.0), Vect2::new(-1.0, 2.0));
+    assert_eq!(
+        v.clamp_min_vec(&Vect2::new(-3.0, 7.0)),
+        Vect2::new(-1.0, 7.0)
+    );
+    assert_eq!(
+        v.clamp_max_vec(&Vect2::new(-3.0, 7.0)),
+        Vect2::new(-3.0, 5.0)
+    );
+}
+
+#[test]
+fn test_bisector() {
+    let b = Vect2::new(2.0, 0.0).bisector(&Vect2::new(0.0, 1.0));
+    let h = std::f32::consts::FRAC_1_SQRT_2;
+    assert!((b - Vect2::new(h, h)).length() < 1e-6);
+    let anti = Vect2::new(1.0, 0.0).bisector(&Vect2::new(-1.0, 0.0));
+    assert_eq!(anti, Vect2::new(-0.0, 1.0));
+}
+
+#[test]
+fn test_bisector_large_inputs() {
+    let b = Vect2::new(3e30, 0.0).bisector(&Vect2::new(0.0, 2e30));
+    let h = std::f32::consts::FRAC_1_SQRT_2;
+    assert!((b - Vect2::new(h, h)).length() < 1e-6);
+}
+
+#[test]
+fn test_index_range() {
+    let v = Vect2::new(1.0, 2.0);
+    assert_eq!(&v[0..2], &[1.0, 2.0]);
+    assert_eq!(&v[1..2], &[2.0]);
+}
+
+#[test]
+fn test_abs_diff() {
+    assert_eq!(
+        Vect2::new(-1.0, 2.0).abs_diff(&Vect2::new(1.0, -2.0)),
+        Vect2::new(2.0, 4.0)
+    );
+}
+
+#[test]
+fn test_max_abs() {
+    let a = Vect2::new(-5.0, 1.0);
+    assert_eq!(a.max_abs(&Vect2::new(3.0, -2.0)), Vect2::new(-5.0, -2.0));
+}
+
+#[test]
+fn test_flip() {
+    assert_eq!(
+        Vect2::new(1.0, -2.0).flip(false, true),
+        Vect2::new(1.0, 2.0)
+    );
+}
+
+#[test]
+fn test_area_accumulator() {
+    let mut acc = AreaAccumulator::new();
+    assert_eq!(acc.area(), 0.0);
+    for p in [
+        Vect2::new(0.0, 0.0),
+        Vect2::new(1.0, 0.0),
+        Vect2::new(1.0, 1.0),
+        Vect2::new(0.0, 1.0),
+    ] {
+        acc.push(p);
+    }
+    assert_eq!(acc.area(), 1.0);
+
+    let mut cw = AreaAccumulator::new();
+    for p in [
+        Vect2::new(0.0, 0.0),
+        Vect2::new(0.0, 2.0),
+        Vect2::new(2.0, 2.0),
+        Vect2::new(2.0, 0.0),
+    ] {
+        cw.push(p);
+    }
+    assert_eq!(cw.area(), -4.0);
+}
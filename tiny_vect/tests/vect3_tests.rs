@@ -1,4 +1,6 @@
-use tiny_vect::Vect3;
+use std::collections::HashMap;
+
+use tiny_vect::{DisplaySlice, OrdVect3, Vect2, Vect3};
 
 const EPS: f32 = 1e-6;
 
@@ -21,6 +23,18 @@ fn from_array_i32() {
     assert_eq!(v, Vect3::new(7.0, 8.0, 9.0));
 }
 
+#[test]
+fn from_array_u8() {
+    let v = Vect3::from([255u8, 0, 128]);
+    assert_eq!(v, Vect3::new(255.0, 0.0, 128.0));
+}
+
+#[test]
+fn from_array_i16() {
+    let v: Vect3 = [-3i16, 4, -5].into();
+    assert_eq!(v, Vect3::new(-3.0, 4.0, -5.0));
+}
+
 #[test]
 fn from_tuple_i32() {
     let v = Vect3::from((1, 2, 3));
@@ -34,6 +48,27 @@ fn into_array() {
     assert_eq!(arr, [1.1, 2.2, 3.3]);
 }
 
+#[test]
+fn into_f64_array_and_tuple() {
+    let v = Vect3::new(1.1, -2.5, 3.3);
+    let arr: [f64; 3] = v.into();
+    assert_eq!(arr, [1.1f32 as f64, -2.5, 3.3f32 as f64]);
+    let t: (f64, f64, f64) = v.into();
+    assert_eq!(t, (arr[0], arr[1], arr[2]));
+}
+
+#[test]
+fn from_vect2_and_back() {
+    assert_eq!(Vect3::from(Vect2::new(1.0, 2.0)), Vect3::new(1.0, 2.0, 0.0));
+    let flat: Vect2 = Vect3::new(1.0, 2.0, 3.0).into();
+    assert_eq!(flat, Vect2::new(1.0, 2.0));
+
+    fn lift(v: impl Into<Vect3>) -> Vect3 {
+        v.into()
+    }
+    assert_eq!(lift(Vect2::new(4.0, 5.0)), Vect3::new(4.0, 5.0, 0.0));
+}
+
 // --- TryFrom slices ---
 #[test]
 fn try_from_f32_slice_ok() {
@@ -67,6 +102,21 @@ fn test_sub() {
     assert_eq!(a - b, Vect3::new(3.0, 3.0, 3.0));
 }
 
+#[test]
+fn test_add_sub_by_ref() {
+    let a = Vect3::new(1.0, 2.0, 3.0);
+    let other = Vect3::new(4.0, -5.0, 6.0);
+    let borrowed = &other;
+    assert_eq!(a + borrowed, a + other);
+    assert_eq!(a - borrowed, a - other);
+
+    let mut v = a;
+    v += borrowed;
+    assert_eq!(v, a + other);
+    v -= borrowed;
+    assert_eq!(v, a);
+}
+
 #[test]
 fn test_mul() {
     let v = Vect3::new(1.0, -2.0, 3.0);
@@ -79,6 +129,22 @@ fn test_div() {
     assert_eq!(v / 2.0, Vect3::new(1.0, 2.0, 3.0));
 }
 
+#[test]
+fn test_mul_div_assign_componentwise() {
+    let mut v = Vect3::new(2.0, 3.0, 4.0);
+    v *= Vect3::new(2.0, 2.0, 2.0);
+    assert_eq!(v, Vect3::new(4.0, 6.0, 8.0));
+    v /= Vect3::new(4.0, 2.0, 0.5);
+    assert_eq!(v, Vect3::new(1.0, 3.0, 16.0));
+}
+
+#[test]
+#[should_panic(expected = "Vect3 division by a zero component")]
+fn test_div_assign_zero_component() {
+    let mut v = Vect3::new(1.0, 1.0, 1.0);
+    v /= Vect3::new(1.0, 0.0, 1.0);
+}
+
 #[test]
 fn test_neg() {
     let v = Vect3::new(1.0, -2.0, 3.0);
@@ -99,6 +165,48 @@ fn test_checked_add_panic() {
     let _ = m.debug_checked_add(m);
 }
 
+#[test]
+fn test_splat() {
+    assert_eq!(Vect3::splat(2.0), Vect3::new(2.0, 2.0, 2.0));
+}
+
+#[test]
+fn test_from_fn() {
+    assert_eq!(
+        Vect3::from_fn(|i| i as f32 * 2.0),
+        Vect3::new(0.0, 2.0, 4.0)
+    );
+    let data = [9.0, 8.0, 7.0, 6.0];
+    assert_eq!(Vect3::from_fn(|i| data[i + 1]), Vect3::new(8.0, 7.0, 6.0));
+}
+
+#[test]
+fn test_hash_to_unit() {
+    for seed in [0, 1, 42, 0xdead_beef, u32::MAX] {
+        let v = Vect3::hash_to_unit(seed);
+        assert_eq!(v, Vect3::hash_to_unit(seed));
+        assert!((v.length() - 1.0).abs() < EPS);
+    }
+    assert_ne!(Vect3::hash_to_unit(1), Vect3::hash_to_unit(2));
+}
+
+#[test]
+fn test_saturating_add() {
+    let m = Vect3::splat(f32::MAX);
+    assert_eq!(m.saturating_add(m), Vect3::splat(f32::MAX));
+    assert_eq!((-m).saturating_add(-m), Vect3::splat(f32::MIN));
+    assert_eq!(
+        Vect3::new(1.0, 2.0, 3.0).saturating_add(Vect3::splat(1.0)),
+        Vect3::new(2.0, 3.0, 4.0)
+    );
+}
+
+#[test]
+fn test_saturating_mul() {
+    let v = Vect3::new(f32::MAX, -f32::MAX, 1.0);
+    assert_eq!(v.saturating_mul(2.0), Vect3::new(f32::MAX, f32::MIN, 2.0));
+}
+
 // --- Dot, Cross, Length & Normalize ---
 #[test]
 fn test_dot() {
@@ -108,6 +216,15 @@ fn test_dot() {
     );
 }
 
+#[test]
+fn test_dot_f64_keeps_sign() {
+    let a = Vect3::new(1e8, 1.0, -1e8);
+    let b = Vect3::new(1.0, 1.0, 1.0);
+    assert_eq!(a.dot(&b), 0.0);
+    assert_eq!(a.dot_f64(&b), 1.0);
+    assert_eq!((-a).dot_f64(&b), -1.0);
+}
+
 #[test]
 fn test_cross() {
     assert_eq!(
@@ -116,17 +233,189 @@ fn test_cross() {
     );
 }
 
+#[test]
+fn test_scalar_triple() {
+    let x = Vect3::new(1.0, 0.0, 0.0);
+    let y = Vect3::new(0.0, 1.0, 0.0);
+    let z = Vect3::new(0.0, 0.0, 1.0);
+    assert_eq!(Vect3::scalar_triple(&x, &y, &z), 1.0);
+    assert_eq!(Vect3::scalar_triple(&y, &x, &z), -1.0);
+    let coplanar = Vect3::new(1.0, 1.0, 0.0);
+    assert_eq!(Vect3::scalar_triple(&x, &y, &coplanar), 0.0);
+}
+
+#[test]
+fn test_are_collinear() {
+    let a = Vect3::new(0.0, 0.0, 0.0);
+    let b = Vect3::new(1.0, 0.0, 0.0);
+    let c = Vect3::new(5.0, 0.0, 0.0);
+    assert!(Vect3::are_collinear(&a, &b, &c, EPS));
+    let corner = Vect3::new(0.0, 1.0, 0.0);
+    assert!(!Vect3::are_collinear(&a, &b, &corner, EPS));
+}
+
+#[test]
+fn test_are_coplanar() {
+    let a = Vect3::new(0.0, 0.0, 1.0);
+    let b = Vect3::new(1.0, 0.0, 1.0);
+    let c = Vect3::new(1.0, 1.0, 1.0);
+    let d = Vect3::new(0.0, 1.0, 1.0);
+    assert!(Vect3::are_coplanar(&a, &b, &c, &d, EPS));
+    let apex = Vect3::new(0.5, 0.5, 2.0);
+    assert!(!Vect3::are_coplanar(&a, &b, &c, &apex, EPS));
+}
+
+#[test]
+fn test_are_coplanar_degenerate() {
+    let a = Vect3::default();
+    let b = Vect3::new(1.0, 1.0, 1.0);
+    let c = Vect3::new(2.0, 2.0, 2.0);
+    assert!(Vect3::are_coplanar(
+        &a,
+        &b,
+        &c,
+        &Vect3::new(0.0, 5.0, -1.0),
+        EPS
+    ));
+    assert!(Vect3::are_coplanar(&a, &a, &a, &a, EPS));
+}
+
+#[test]
+fn test_tetrahedron_volume() {
+    let o = Vect3::default();
+    let x = Vect3::new(1.0, 0.0, 0.0);
+    let y = Vect3::new(0.0, 1.0, 0.0);
+    let z = Vect3::new(0.0, 0.0, 1.0);
+    assert!((Vect3::tetrahedron_volume(&o, &x, &y, &z) - 1.0 / 6.0).abs() < EPS);
+    assert!((Vect3::tetrahedron_volume(&o, &y, &x, &z) + 1.0 / 6.0).abs() < EPS);
+}
+
+#[test]
+fn test_closest_points_on_segments() {
+    let (a, b) = Vect3::closest_points_on_segments(
+        &Vect3::new(-1.0, 0.0, 0.0),
+        &Vect3::new(1.0, 0.0, 0.0),
+        &Vect3::new(0.5, -1.0, 2.0),
+        &Vect3::new(0.5, 1.0, 2.0),
+    );
+    assert!(a.distance(&Vect3::new(0.5, 0.0, 0.0)) < EPS);
+    assert!(b.distance(&Vect3::new(0.5, 0.0, 2.0)) < EPS);
+    assert!((a.distance(&b) - 2.0).abs() < EPS);
+}
+
+#[test]
+fn test_closest_points_on_segments_degenerate() {
+    let p = Vect3::new(0.0, 1.0, 0.0);
+    let (a, b) = Vect3::closest_points_on_segments(
+        &p,
+        &p,
+        &Vect3::new(-1.0, 0.0, 0.0),
+        &Vect3::new(1.0, 0.0, 0.0),
+    );
+    assert_eq!(a, p);
+    assert!(b.distance(&Vect3::default()) < EPS);
+
+    // Parallel segments still give a finite pair at distance 1
+    let (a, b) = Vect3::closest_points_on_segments(
+        &Vect3::new(0.0, 0.0, 0.0),
+        &Vect3::new(2.0, 0.0, 0.0),
+        &Vect3::new(1.0, 1.0, 0.0),
+        &Vect3::new(3.0, 1.0, 0.0),
+    );
+    assert!((a.distance(&b) - 1.0).abs() < EPS);
+}
+
+#[test]
+fn test_distance_to_line() {
+    let x_axis = Vect3::new(3.0, 0.0, 0.0);
+    assert!(
+        (Vect3::distance_to_line(&Vect3::new(0.0, 1.0, 0.0), &Vect3::default(), &x_axis) - 1.0)
+            .abs()
+            < EPS
+    );
+    let p = Vect3::new(5.0, 3.0, 4.0);
+    let on_line = Vect3::new(-2.0, 0.0, 0.0);
+    assert!((Vect3::distance_to_line(&p, &on_line, &x_axis) - 5.0).abs() < EPS);
+}
+
+#[test]
+#[should_panic(expected = "Vect3::distance_to_line direction is zero")]
+fn test_distance_to_line_zero_direction() {
+    let _ = Vect3::distance_to_line(&Vect3::default(), &Vect3::default(), &Vect3::default());
+}
+
 #[test]
 fn test_length() {
     assert!((Vect3::new(1.0, 2.0, 2.0).length() - 3.0).abs() < EPS);
 }
 
+#[test]
+fn test_length_f64() {
+    let v = Vect3::new(1.5, -2.25, 4.0);
+    assert!((v.length_f64() - v.length() as f64).abs() < 1e-6);
+
+    let big = Vect3::new(f32::MAX, f32::MAX, 0.0).length_f64();
+    assert!(big.is_finite());
+    assert!((big - f32::MAX as f64 * 2f64.sqrt()).abs() / big < 1e-12);
+}
+
 #[test]
 fn test_normalize() {
     let v = Vect3::new(0.0, 3.0, 4.0).normalize();
     assert!((v.length() - 1.0).abs() < EPS);
 }
 
+#[test]
+fn test_normalize_or_zero() {
+    assert!(Vect3::default().normalize_or_zero().is_zero());
+    assert!(Vect3::new(f32::NAN, 1.0, 0.0).normalize_or_zero().is_zero());
+    let big = Vect3::splat(f32::MAX).normalize_or_zero();
+    assert!((big.length() - 1.0).abs() < EPS);
+    assert!((big.x - 1.0 / 3.0f32.sqrt()).abs() < EPS);
+    assert_eq!(
+        Vect3::new(3e19, 4e19, 0.0).normalize_or_zero(),
+        Vect3::new(0.6, 0.8, 0.0)
+    );
+    let v = Vect3::new(0.0, 3.0, 4.0).normalize_or_zero();
+    assert_eq!(v, Vect3::new(0.0, 0.6, 0.8));
+}
+
+#[test]
+fn test_safe_normalize() {
+    let v = Vect3::splat(f32::MAX).safe_normalize();
+    assert!((v.length() - 1.0).abs() < EPS);
+    assert!((v.x - 1.0 / 3.0f32.sqrt()).abs() < EPS);
+
+    let tiny = Vect3::new(0.0, 3e-30, 4e-30).safe_normalize();
+    assert!((tiny - Vect3::new(0.0, 0.6, 0.8)).length() < EPS);
+    assert!(Vect3::default().safe_normalize().is_zero());
+}
+
+#[test]
+fn test_safe_normalize_non_finite() {
+    assert!(
+        Vect3::new(f32::INFINITY, 1.0, 0.0)
+            .safe_normalize()
+            .is_zero()
+    );
+    assert!(Vect3::splat(f32::NEG_INFINITY).safe_normalize().is_zero());
+    assert!(Vect3::new(f32::NAN, 1.0, 0.0).safe_normalize().is_zero());
+}
+
+#[test]
+fn test_direction_or() {
+    let up = Vect3::new(0.0, 1.0, 0.0);
+    assert_eq!(Vect3::default().direction_or(up), up);
+    assert_eq!(
+        Vect3::new(0.0, 0.0, -5.0).direction_or(up),
+        Vect3::new(0.0, 0.0, -1.0)
+    );
+    assert_eq!(
+        Vect3::new(0.0, 3e19, 4e19).direction_or(up),
+        Vect3::new(0.0, 0.6, 0.8)
+    );
+}
+
 // --- Distance & Distance Squared ---
 #[test]
 fn test_distance_sq() {
@@ -158,6 +447,33 @@ fn test_lerp() {
     assert_eq!(m, Vect3::new(1.0, 1.0, 1.0));
 }
 
+#[test]
+fn test_lerp_vec() {
+    let a = Vect3::new(0.0, 2.0, 4.0);
+    let b = Vect3::new(10.0, 6.0, 8.0);
+    let r = a.lerp_vec(&b, &Vect3::new(0.0, 0.5, 1.0));
+    assert_eq!(r, Vect3::new(a.x, 4.0, b.z));
+}
+
+#[test]
+fn test_ema() {
+    let v = Vect3::new(1.0, 2.0, 3.0);
+    let s = Vect3::new(5.0, 6.0, 7.0);
+    assert_eq!(v.ema(&s, 0.0), v);
+    assert_eq!(v.ema(&s, 1.0), s);
+    assert_eq!(v.ema(&s, 0.25), Vect3::new(2.0, 3.0, 4.0));
+    assert_eq!(v.ema(&s, 3.0), s);
+}
+
+#[test]
+fn test_ema_update() {
+    let mut v = Vect3::default();
+    let s = Vect3::splat(8.0);
+    v.ema_update(&s, 0.5);
+    v.ema_update(&s, 0.5);
+    assert_eq!(v, Vect3::splat(6.0));
+}
+
 #[test]
 fn test_reflect() {
     let v = Vect3::new(1.0, -1.0, 0.0);
@@ -165,6 +481,134 @@ fn test_reflect() {
     assert_eq!(v.reflect(&n), Vect3::new(1.0, 1.0, 0.0));
 }
 
+#[test]
+fn test_reflect_precise_shallow_angle() {
+    let n = Vect3::new(1.0, 2.0, 3.0).normalize();
+    let tangent = n.cross(&Vect3::new(0.0, 0.0, 1.0));
+    let mut err_reflect = 0.0;
+    let mut err_precise = 0.0;
+    for i in 1..=20 {
+        // Grazing: large tangential part, tiny normal part
+        let v = tangent * (37.0 + i as f32) - n * (0.001 * i as f32);
+        let d = v.dot_f64(&n);
+        let exact = [
+            v.x as f64 - 2.0 * d * n.x as f64,
+            v.y as f64 - 2.0 * d * n.y as f64,
+            v.z as f64 - 2.0 * d * n.z as f64,
+        ];
+        let err = |r: Vect3| {
+            (r.x as f64 - exact[0]).abs()
+                + (r.y as f64 - exact[1]).abs()
+                + (r.z as f64 - exact[2]).abs()
+        };
+        err_reflect += err(v.reflect(&n));
+        err_precise += err(v.reflect_precise(&n));
+        assert!(v.reflect_precise(&n).distance(&v.reflect(&n)) < 1e-4);
+    }
+    assert!(err_precise < err_reflect);
+}
+
+#[test]
+fn test_reflect_unnormalized_matches_reflect() {
+    let v = Vect3::new(1.5, -2.0, 0.75);
+    for n in [
+        Vect3::new(0.0, 3.0, 0.0),
+        Vect3::new(1.0, 2.0, -2.0),
+        Vect3::new(-0.2, 0.1, 5.0),
+    ] {
+        assert!(v.reflect_unnormalized(&n).distance(&v.reflect(&n)) < 1e-5);
+    }
+    assert_eq!(v.reflect_unnormalized(&Vect3::default()), v);
+}
+
+#[test]
+fn test_reflect_with_cos() {
+    let v = Vect3::new(1.0, -1.0, 0.0);
+    let (r, cos) = v.reflect_with_cos(&Vect3::new(0.0, 2.0, 0.0));
+    assert_eq!(r, v.reflect(&Vect3::new(0.0, 1.0, 0.0)));
+    assert!((cos - std::f32::consts::FRAC_1_SQRT_2).abs() < EPS);
+    assert_eq!(
+        Vect3::default()
+            .reflect_with_cos(&Vect3::new(0.0, 1.0, 0.0))
+            .1,
+        0.0
+    );
+}
+
+#[test]
+fn test_reflect_split() {
+    let up = Vect3::new(0.0, 1.0, 0.0);
+    let (r, cos) = Vect3::new(0.0, -3.0, 0.0).reflect_split(&up);
+    assert_eq!(r, Vect3::new(0.0, 3.0, 0.0));
+    assert!((cos - 1.0).abs() < EPS);
+
+    let (_, back) = Vect3::new(1.0, 1.0, 0.0).reflect_split(&up);
+    assert_eq!(back, 0.0);
+    assert_eq!(Vect3::default().reflect_split(&up), (Vect3::default(), 0.0));
+}
+
+#[test]
+fn test_mirror_across_plane() {
+    let p = Vect3::new(0.0, 2.0, 0.0);
+    let r = p.mirror_across_plane(&Vect3::default(), &Vect3::new(0.0, 1.0, 0.0));
+    assert_eq!(r, Vect3::new(0.0, -2.0, 0.0));
+
+    // Plane x = 1, non-unit normal
+    let q = Vect3::new(3.0, 4.0, 5.0);
+    let r = q.mirror_across_plane(&Vect3::new(1.0, 9.0, 9.0), &Vect3::new(-2.0, 0.0, 0.0));
+    assert_eq!(r, Vect3::new(-1.0, 4.0, 5.0));
+}
+
+#[test]
+fn test_bounce() {
+    let v = Vect3::new(2.0, -4.0, 1.0);
+    let n = Vect3::new(0.0, 3.0, 0.0);
+    assert_eq!(v.bounce(&n, 0.5), Vect3::new(2.0, 2.0, 1.0));
+    assert_eq!(v.bounce(&n, 1.0), v.reflect(&n));
+}
+
+#[test]
+fn test_collide() {
+    let v = Vect3::new(3.0, -2.0, 1.0);
+    let n = Vect3::new(0.0, 1.0, 0.0);
+    assert_eq!(v.collide(&n, 1.0, 1.0), Vect3::new(0.0, 2.0, 0.0));
+    assert_eq!(v.collide(&n, 1.0, 0.0), v.reflect(&n));
+    assert_eq!(v.collide(&n, 0.5, 0.5), Vect3::new(1.5, 1.0, 0.5));
+}
+
+#[test]
+fn test_reflect_slice() {
+    let mut vs = [
+        Vect3::new(1.0, -2.0, 3.0),
+        Vect3::new(0.0, 5.0, -1.0),
+        Vect3::new(-4.0, 0.0, 0.0),
+    ];
+    Vect3::reflect_slice(&mut vs, &Vect3::new(0.0, 1.0, 0.0));
+    assert_eq!(
+        vs,
+        [
+            Vect3::new(1.0, 2.0, 3.0),
+            Vect3::new(0.0, -5.0, -1.0),
+            Vect3::new(-4.0, 0.0, 0.0),
+        ]
+    );
+}
+
+#[test]
+fn test_reflect_against_normals() {
+    let up = Vect3::new(0.0, 1.0, 0.0);
+    let mut out = [Vect3::default(); 3];
+    Vect3::reflect_against_normals(&Vect3::new(0.0, -1.0, 0.0), &[up; 3], &mut out);
+    assert_eq!(out, [up; 3]);
+}
+
+#[test]
+#[should_panic(expected = "normal is not normalized")]
+fn test_reflect_against_normals_non_unit() {
+    let mut out = [Vect3::default(); 1];
+    Vect3::reflect_against_normals(&Vect3::splat(1.0), &[Vect3::splat(2.0)], &mut out);
+}
+
 #[test]
 fn test_project() {
     let v = Vect3::new(2.0, 0.0, 0.0);
@@ -173,6 +617,32 @@ fn test_project() {
     assert!((p.x - 1.0).abs() < EPS && (p.y - 1.0).abs() < EPS);
 }
 
+#[test]
+fn test_project_onto_unit_matches_project() {
+    let v = Vect3::new(3.0, -1.0, 2.0);
+    let unit = Vect3::new(1.0, 2.0, 2.0).normalize();
+    assert!(v.project_onto_unit(&unit).distance(&v.project(&unit)) < EPS);
+}
+
+#[test]
+fn test_into_variants() {
+    let a = Vect3::new(1.0, 2.0, 3.0);
+    let b = Vect3::new(4.0, -5.0, 6.0);
+    let mut out = Vect3::default();
+    a.add_into(&b, &mut out);
+    assert_eq!(out, a + b);
+    a.sub_into(&b, &mut out);
+    assert_eq!(out, a - b);
+    a.project_into(&b, &mut out);
+    assert_eq!(out, a.project(&b));
+}
+
+#[test]
+#[should_panic(expected = "target is not normalized")]
+fn test_project_onto_unit_rejects_non_unit() {
+    let _ = Vect3::new(1.0, 0.0, 0.0).project_onto_unit(&Vect3::new(2.0, 0.0, 0.0));
+}
+
 // --- Indexing ---
 #[test]
 fn test_index() {
@@ -189,20 +659,833 @@ fn test_index_panic() {
     let _ = v[3];
 }
 
+#[test]
+fn test_index_range() {
+    let v = Vect3::new(1.0, 2.0, 3.0);
+    assert_eq!(&v[1..3], &[2.0, 3.0]);
+    assert_eq!(&v[0..0], &[] as &[f32]);
+    assert_eq!(v.as_slice(), &[1.0, 2.0, 3.0]);
+}
+
+#[test]
+#[should_panic]
+fn test_index_range_panic() {
+    let v = Vect3::default();
+    let _ = &v[2..4];
+}
+
+#[test]
+fn test_iter_mut() {
+    let mut v = Vect3::new(1.0, -2.0, 3.0);
+    for c in v.iter_mut() {
+        *c *= 2.0;
+    }
+    assert_eq!(v, Vect3::new(2.0, -4.0, 6.0));
+}
+
 // --- Utility checks ---
 #[test]
 fn test_is_zero() {
     assert!(Vect3::new(0.0, 0.0, 0.0).is_zero());
 }
 
+#[test]
+fn test_is_approx_zero() {
+    assert!(Vect3::new(1e-7, 0.0, 0.0).is_approx_zero());
+    assert!(!Vect3::new(0.1, 0.0, 0.0).is_approx_zero());
+    assert!(Vect3::new(0.1, 0.0, 0.0).is_near_zero(0.2));
+    assert!(!Vect3::splat(f32::MAX).is_near_zero(1.0));
+}
+
 #[test]
 fn test_is_normalized() {
     assert!(Vect3::new(1.0, 0.0, 0.0).is_normalized());
 }
 
+#[test]
+fn test_is_normalized_after_normalize() {
+    assert!(Vect3::new(1.0, 2.0, 3.0).normalize().is_normalized());
+    assert!(!Vect3::new(1.0, 2.0, 3.0).is_normalized());
+}
+
+#[test]
+fn test_is_normalized_tol() {
+    let v = Vect3::new(1.001, 0.0, 0.0);
+    assert!(!v.is_normalized());
+    assert!(v.is_normalized_tol(1e-2));
+}
+
 #[test]
 fn test_is_parallel() {
     let a = Vect3::new(1.0, 1.0, 1.0);
     let b = Vect3::new(2.0, 2.0, 2.0);
     assert!(a.is_parallel(&b));
 }
+
+#[test]
+fn test_is_parallel_long_vectors() {
+    let a = Vect3::new(1234.5, 6789.1, 2345.6);
+    let b = a * 3.7;
+    assert!(a.is_parallel(&b));
+}
+
+#[test]
+fn test_is_parallel_tol() {
+    let a = Vect3::new(1000.0, 1000.0, 1000.0);
+    let b = Vect3::new(1000.0, 1000.0, 1001.0);
+    assert!(!a.is_parallel(&b));
+    assert!(a.is_parallel_tol(&b, 1e-3));
+    assert!(a.is_parallel_tol(&-b, 1e-3));
+    assert!(!a.is_parallel_tol(&b, 1e-4));
+}
+
+#[test]
+fn test_is_parallel_tiny_and_huge() {
+    let tiny_x = Vect3::new(1e-25, 0.0, 0.0);
+    assert!(!tiny_x.is_parallel(&Vect3::new(0.0, 1e-25, 0.0)));
+    assert!(tiny_x.is_parallel(&Vect3::new(-3e-25, 0.0, 0.0)));
+    assert!(!tiny_x.is_parallel_tol(&Vect3::new(0.0, 1e-25, 0.0), 1e-3));
+
+    let huge = Vect3::new(1e20, 2e20, -1e20);
+    assert!(huge.is_parallel(&(huge * 3.0)));
+    assert!(!huge.is_parallel(&Vect3::new(-2e20, 1e20, 0.0)));
+    assert!(huge.is_parallel_tol(&-huge, 1e-6));
+    assert!(!huge.is_parallel_tol(&Vect3::new(-2e20, 1e20, 0.0), 1e-3));
+}
+
+// --- Arc length ---
+#[test]
+fn test_segments() {
+    let pts: Vec<Vect3> = (0..4).map(|i| Vect3::splat(i as f32)).collect();
+    let segs: Vec<(Vect3, Vect3)> = Vect3::segments(&pts).collect();
+    assert_eq!(
+        segs,
+        vec![(pts[0], pts[1]), (pts[1], pts[2]), (pts[2], pts[3])]
+    );
+    assert_eq!(Vect3::segments(&pts[..1]).count(), 0);
+}
+
+#[test]
+fn test_curve_arc_length() {
+    let pts = [
+        Vect3::new(0.0, 0.0, 0.0),
+        Vect3::new(3.0, 4.0, 0.0),
+        Vect3::new(3.0, 4.0, 2.0),
+    ];
+    assert!((Vect3::curve_arc_length(&pts) - 7.0).abs() < EPS);
+    assert_eq!(Vect3::curve_arc_length(&pts[..1]), 0.0);
+}
+
+#[test]
+fn test_reparameterize_straight_line() {
+    let a = Vect3::new(0.0, 0.0, 0.0);
+    let b = Vect3::new(4.0, 2.0, 0.0);
+    let ts = Vect3::reparameterize_by_arc_length(|t| a.lerp(&b, t), 4);
+    assert_eq!(ts.len(), 5);
+    for (i, t) in ts.iter().enumerate() {
+        assert!((t - i as f32 / 4.0).abs() < EPS);
+    }
+}
+
+#[test]
+fn test_linspace() {
+    let a = Vect3::default();
+    let b = Vect3::new(4.0, 0.0, 0.0);
+    let pts = Vect3::linspace(&a, &b, 5);
+    let xs: Vec<f32> = pts.iter().map(|p| p.x).collect();
+    assert_eq!(xs, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(Vect3::linspace(&a, &b, 1), vec![a]);
+    assert!(Vect3::linspace(&a, &b, 0).is_empty());
+}
+
+// --- Weighted blends ---
+#[test]
+fn test_weighted_sum_matches_lerp() {
+    let a = Vect3::new(1.0, 2.0, 3.0);
+    let b = Vect3::new(5.0, -2.0, 7.0);
+    let t = 0.25;
+    let blended = Vect3::weighted_sum(&[a, b], &[1.0 - t, t]);
+    assert!(blended.distance(&a.lerp(&b, t)) < EPS);
+}
+
+#[test]
+fn test_weighted_average() {
+    let a = Vect3::new(0.0, 0.0, 0.0);
+    let b = Vect3::new(4.0, 8.0, 0.0);
+    assert_eq!(
+        Vect3::weighted_average(&[a, b], &[3.0, 1.0]),
+        Vect3::new(1.0, 2.0, 0.0)
+    );
+    assert_eq!(
+        Vect3::weighted_average(&[a, b], &[0.0, 0.0]),
+        Vect3::default()
+    );
+}
+
+#[test]
+#[should_panic(expected = "vectors and weights differ in length")]
+fn test_weighted_sum_length_mismatch() {
+    let _ = Vect3::weighted_sum(&[Vect3::default()], &[1.0, 2.0]);
+}
+
+// --- Rotations ---
+#[test]
+fn test_rotate_around_axis() {
+    let v = Vect3::new(1.0, 0.0, 0.0);
+    let r = v.rotate_around_axis(&Vect3::new(0.0, 0.0, 2.0), std::f32::consts::FRAC_PI_2);
+    assert!(r.distance(&Vect3::new(0.0, 1.0, 0.0)) < EPS);
+}
+
+#[test]
+fn test_rotate_axes() {
+    use std::f32::consts::FRAC_PI_2;
+    let x = Vect3::new(1.0, 0.0, 0.0);
+    let y = Vect3::new(0.0, 1.0, 0.0);
+    let z = Vect3::new(0.0, 0.0, 1.0);
+    assert!(x.rotate_z(FRAC_PI_2).distance(&y) < EPS);
+    assert!(y.rotate_x(FRAC_PI_2).distance(&z) < EPS);
+    assert!(z.rotate_y(FRAC_PI_2).distance(&x) < EPS);
+}
+
+#[test]
+fn test_rotate_axes_match_axis_angle() {
+    let v = Vect3::new(1.0, -2.0, 0.5);
+    let a = 0.7;
+    let x = Vect3::new(1.0, 0.0, 0.0);
+    let y = Vect3::new(0.0, 1.0, 0.0);
+    let z = Vect3::new(0.0, 0.0, 1.0);
+    assert!(v.rotate_x(a).distance(&v.rotate_around_axis(&x, a)) < 1e-5);
+    assert!(v.rotate_y(a).distance(&v.rotate_around_axis(&y, a)) < 1e-5);
+    assert!(v.rotate_z(a).distance(&v.rotate_around_axis(&z, a)) < 1e-5);
+}
+
+#[test]
+fn test_rotate_towards_step() {
+    let start = Vect3::new(2.0, 0.0, 0.0);
+    let target = Vect3::new(0.0, 1.0, 0.0);
+    let step = 30f32.to_radians();
+    let r = start.rotate_towards(&target, step);
+    assert!((r.angle_between(&start) - step).abs() < 1e-5);
+    assert!((r.angle_between(&target) - 2.0 * step).abs() < 1e-5);
+    assert!((r.length() - 2.0).abs() < EPS);
+}
+
+#[test]
+fn test_rotate_towards_snaps() {
+    let start = Vect3::new(2.0, 0.0, 0.0);
+    let target = Vect3::new(1.0, 1.0, 0.0);
+    let r = start.rotate_towards(&target, std::f32::consts::FRAC_PI_2);
+    assert!(r.distance(&(target.normalize() * 2.0)) < EPS);
+}
+
+#[test]
+fn test_rotate_towards_antiparallel() {
+    let start = Vect3::new(1.0, 0.0, 0.0);
+    let r = start.rotate_towards(&-start, 0.5);
+    assert!((r.angle_between(&start) - 0.5).abs() < 1e-5);
+}
+
+#[test]
+fn test_orbit_quarter_turn() {
+    let center = Vect3::new(1.0, 1.0, 5.0);
+    let p = Vect3::new(3.0, 1.0, 5.0);
+    let r = p.orbit(
+        &center,
+        &Vect3::new(0.0, 0.0, 1.0),
+        std::f32::consts::FRAC_PI_2,
+    );
+    assert!(r.distance(&Vect3::new(1.0, 3.0, 5.0)) < EPS);
+    assert!((r.distance(&center) - p.distance(&center)).abs() < EPS);
+}
+
+#[test]
+fn test_bisector() {
+    let b = Vect3::new(1.0, 0.0, 0.0).bisector(&Vect3::new(0.0, 3.0, 0.0));
+    assert!(b.distance(&Vect3::new(1.0, 1.0, 0.0).normalize()) < EPS);
+}
+
+#[test]
+fn test_bisector_antiparallel() {
+    let a = Vect3::new(0.0, 0.0, 2.0);
+    let b = a.bisector(&-a);
+    assert!(b.is_normalized());
+    assert!(b.dot(&a).abs() < EPS);
+}
+
+#[test]
+fn test_bisector_large_inputs() {
+    let b = Vect3::new(3e30, 0.0, 0.0).bisector(&Vect3::new(0.0, 0.0, 2e30));
+    assert!(b.distance(&Vect3::new(1.0, 0.0, 1.0).normalize()) < EPS);
+    let anti = Vect3::splat(f32::MAX).bisector(&Vect3::splat(-f32::MAX));
+    assert!(anti.is_normalized());
+}
+
+// --- Tangent space ---
+#[test]
+fn test_tangent_basis_axis_aligned() {
+    let (t, b, n) = Vect3::tangent_basis(
+        &Vect3::new(0.0, 0.0, 0.0),
+        &Vect3::new(2.0, 0.0, 0.0),
+        &Vect3::new(0.0, 2.0, 0.0),
+        &Vect2::new(0.0, 0.0),
+        &Vect2::new(1.0, 0.0),
+        &Vect2::new(0.0, 1.0),
+    );
+    assert!(t.distance(&Vect3::new(1.0, 0.0, 0.0)) < EPS);
+    assert!(b.distance(&Vect3::new(0.0, 1.0, 0.0)) < EPS);
+    assert!(n.distance(&Vect3::new(0.0, 0.0, 1.0)) < EPS);
+}
+
+#[test]
+fn test_tangent_basis_small_uv_deltas() {
+    let (t, b, n) = Vect3::tangent_basis(
+        &Vect3::new(0.0, 0.0, 0.0),
+        &Vect3::new(2.0, 0.0, 0.0),
+        &Vect3::new(0.0, 2.0, 0.0),
+        &Vect2::new(0.0, 0.0),
+        &Vect2::new(1e-4, 0.0),
+        &Vect2::new(0.0, 2e-4),
+    );
+    assert!(t.distance(&Vect3::new(1.0, 0.0, 0.0)) < EPS);
+    assert!(b.distance(&Vect3::new(0.0, 1.0, 0.0)) < EPS);
+    assert!(n.distance(&Vect3::new(0.0, 0.0, 1.0)) < EPS);
+}
+
+#[test]
+fn test_tangent_basis_degenerate_uv() {
+    let uv = Vect2::new(0.5, 0.5);
+    let (t, b, n) = Vect3::tangent_basis(
+        &Vect3::new(0.0, 0.0, 0.0),
+        &Vect3::new(1.0, 0.0, 0.0),
+        &Vect3::new(0.0, 0.0, 1.0),
+        &uv,
+        &uv,
+        &uv,
+    );
+    assert!(t.is_normalized() && b.is_normalized() && n.is_normalized());
+    assert!(t.dot(&b).abs() < EPS && t.dot(&n).abs() < EPS && b.dot(&n).abs() < EPS);
+}
+
+#[test]
+fn test_tangent_basis_coincident_positions() {
+    let p = Vect3::new(1.0, 1.0, 1.0);
+    let (t, b, n) = Vect3::tangent_basis(
+        &p,
+        &p,
+        &p,
+        &Vect2::new(0.0, 0.0),
+        &Vect2::new(1.0, 0.0),
+        &Vect2::new(0.0, 1.0),
+    );
+    assert!(t.is_normalized() && b.is_normalized() && n.is_normalized());
+    assert!(t.dot(&b).abs() < EPS && t.dot(&n).abs() < EPS && b.dot(&n).abs() < EPS);
+}
+
+// --- Component reductions ---
+#[test]
+fn test_sum_and_product_elements() {
+    let v = Vect3::new(1.0, 2.0, 3.0);
+    assert_eq!(v.sum_elements(), 6.0);
+    assert_eq!(v.product_elements(), 6.0);
+}
+
+#[test]
+fn test_min_max_component() {
+    let v = Vect3::new(4.0, -1.0, 2.5);
+    assert_eq!(v.min_component(), -1.0);
+    assert_eq!(v.max_component(), 4.0);
+}
+
+#[test]
+fn test_abs_diff() {
+    let a = Vect3::new(1.0, 5.0, 3.0);
+    let b = Vect3::new(4.0, 1.0, 3.0);
+    assert_eq!(a.abs_diff(&b), Vect3::new(3.0, 4.0, 0.0));
+    // L-infinity error in one line
+    assert_eq!(a.abs_diff(&b).max_component(), 4.0);
+}
+
+#[test]
+fn test_max_abs() {
+    let a = Vect3::new(-5.0, 1.0, 2.0);
+    let b = Vect3::new(3.0, -2.0, -2.0);
+    assert_eq!(a.max_abs(&b), Vect3::new(-5.0, -2.0, 2.0));
+}
+
+// --- Formatting ---
+#[test]
+fn test_to_bits_round_trip() {
+    let nan = f32::from_bits(0x7fc0_1234);
+    let v = Vect3::new(nan, -0.0, 1.5);
+    let bits = v.to_bits();
+    assert_eq!(bits, [0x7fc0_1234, 0x8000_0000, 1.5f32.to_bits()]);
+    assert_eq!(Vect3::from_bits(bits).to_bits(), bits);
+}
+
+#[test]
+fn test_to_hex_string() {
+    let a = Vect3::new(1.0, 2.0, -0.0);
+    assert_eq!(a.to_hex_string(), "(0x3f800000, 0x40000000, 0x80000000)");
+    let b = Vect3::new(f32::from_bits(1.0f32.to_bits() + 1), 2.0, -0.0);
+    assert_ne!(a.to_hex_string(), b.to_hex_string());
+}
+
+#[test]
+fn test_format_with() {
+    let v = Vect3::new(1.0, 2.0, 3.0);
+    assert_eq!(v.format_with(",", false), "1,2,3");
+    assert_eq!(v.format_with(" ", false), "1 2 3");
+    assert_eq!(v.format_with("; ", true), "(1; 2; 3)");
+    assert_eq!(v.to_string(), "(1, 2, 3)");
+}
+
+#[test]
+fn test_display_slice() {
+    let points = [Vect3::new(1.0, 2.0, 3.0), Vect3::new(-1.0, 0.5, 0.0)];
+    assert_eq!(
+        DisplaySlice(&points).to_string(),
+        "[(1, 2, 3), (-1, 0.5, 0)]"
+    );
+    assert_eq!(DisplaySlice(&[]).to_string(), "[]");
+}
+
+// --- Spatial partitioning ---
+#[test]
+fn test_component() {
+    let v = Vect3::new(1.0, 2.0, 3.0);
+    assert_eq!(v.component(2), 3.0);
+}
+
+#[test]
+fn test_split_axis() {
+    let pts = [
+        Vect3::new(0.0, -10.0, 1.0),
+        Vect3::new(1.0, 5.0, 0.0),
+        Vect3::new(-1.0, 20.0, 2.0),
+    ];
+    assert_eq!(Vect3::split_axis(&pts), 1);
+    assert_eq!(Vect3::split_axis(&[]), 0);
+}
+
+#[test]
+fn test_nearest() {
+    let pts = [
+        Vect3::new(5.0, 0.0, 0.0),
+        Vect3::new(1.0, 1.0, 0.0),
+        Vect3::new(-2.0, 0.0, 0.0),
+    ];
+    assert_eq!(Vect3::default().nearest(&pts), Some((1, 2.0)));
+    assert_eq!(Vect3::new(-3.0, 0.0, 0.0).nearest(&pts), Some((2, 1.0)));
+    assert_eq!(Vect3::default().nearest(&[]), None);
+}
+
+// --- Clamping ---
+#[test]
+fn test_clamp_scalar() {
+    let v = Vect3::new(-5.0, 0.5, 10.0).clamp_scalar(0.0, 1.0);
+    assert_eq!(v, Vect3::new(0.0, 0.5, 1.0));
+    assert!(
+        Vect3::new(f32::NAN, 0.0, 0.0)
+            .clamp_scalar(0.0, 1.0)
+            .x
+            .is_nan()
+    );
+}
+
+#[test]
+fn test_clamp_scalar_nan() {
+    let v = Vect3::new(f32::NAN, -5.0, 10.0).clamp_scalar_nan(0.0, 1.0, 0.0);
+    assert_eq!(v, Vect3::new(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_replace_nan_inf() {
+    let v = Vect3::new(f32::NAN, f32::INFINITY, 1.0);
+    let n = v.replace_nan(0.0);
+    assert_eq!(n.x, 0.0);
+    assert_eq!(n.y, f32::INFINITY);
+    assert_eq!(n.z, 1.0);
+    let i = v.replace_inf(f32::MAX);
+    assert!(i.x.is_nan());
+    assert_eq!(i.y, f32::MAX);
+    assert_eq!(i.z, 1.0);
+    assert_eq!(
+        v.replace_nan(0.0).replace_inf(0.0),
+        Vect3::new(0.0, 0.0, 1.0)
+    );
+}
+
+#[test]
+fn test_clamp_min_max() {
+    let v = Vect3::new(-1.0, 5.0, 2.0);
+    assert_eq!(v.clamp_min(0.0), Vect3::new(0.0, 5.0, 2.0));
+    assert_eq!(v.clamp_max(3.0), Vect3::new(-1.0, 3.0, 2.0));
+}
+
+#[test]
+fn test_clamp_min_max_vec() {
+    let v = Vect3::new(-1.0, 5.0, 2.0);
+    let bound = Vect3::new(0.0, 6.0, 1.0);
+    assert_eq!(v.clamp_min_vec(&bound), Vect3::new(0.0, 6.0, 2.0));
+    assert_eq!(v.clamp_max_vec(&bound), Vect3::new(-1.0, 5.0, 1.0));
+}
+
+#[test]
+fn test_clamp_to_sphere() {
+    let center = Vect3::new(1.0, 1.0, 1.0);
+    let inside = Vect3::new(1.5, 1.0, 1.0);
+    assert_eq!(inside.clamp_to_sphere(&center, 2.0), inside);
+
+    let outside = Vect3::new(1.0, 5.0, 4.0);
+    let clamped = outside.clamp_to_sphere(&center, 2.0);
+    assert!((clamped.distance(&center) - 2.0).abs() < EPS);
+    assert!(clamped.distance(&Vect3::new(1.0, 2.6, 2.2)) < EPS);
+}
+
+// --- Grid snapping ---
+#[test]
+fn test_snap_to_grid_uniform() {
+    let v = Vect3::new(1.4, 2.6, 0.0).snap_to_grid_uniform(1.0);
+    assert_eq!(v, Vect3::new(1.0, 3.0, 0.0));
+}
+
+#[test]
+fn test_snap_to_grid_zero_cell() {
+    let v = Vect3::new(1.3, 2.6, 7.7).snap_to_grid(&Vect3::new(0.5, 0.0, 2.0));
+    assert_eq!(v, Vect3::new(1.5, 2.6, 8.0));
+}
+
+// --- Curves ---
+#[test]
+fn test_hermite_endpoints_and_tangents() {
+    let p0 = Vect3::new(0.0, 0.0, 0.0);
+    let p1 = Vect3::new(1.0, 2.0, 3.0);
+    let m0 = Vect3::new(1.0, 0.0, 0.0);
+    let m1 = Vect3::new(0.0, 0.0, -2.0);
+    assert_eq!(Vect3::hermite(&p0, &m0, &p1, &m1, 0.0), p0);
+    assert_eq!(Vect3::hermite(&p0, &m0, &p1, &m1, 1.0), p1);
+
+    let h = 1e-4;
+    let d0 = (Vect3::hermite(&p0, &m0, &p1, &m1, h) - p0) / h;
+    let d1 = (p1 - Vect3::hermite(&p0, &m0, &p1, &m1, 1.0 - h)) / h;
+    assert!(d0.distance(&m0) < 1e-2);
+    assert!(d1.distance(&m1) < 1e-2);
+}
+
+#[test]
+fn test_hermite_zero_tangents_is_smoothstep() {
+    let p0 = Vect3::new(0.0, 0.0, 0.0);
+    let p1 = Vect3::new(2.0, 4.0, 0.0);
+    let zero = Vect3::default();
+    let t: f32 = 0.25;
+    let s = t * t * (3.0 - 2.0 * t);
+    let r = Vect3::hermite(&p0, &zero, &p1, &zero, t);
+    assert!(r.distance(&p0.lerp(&p1, s)) < EPS);
+}
+
+#[test]
+fn test_catmull_rom_passes_through_points() {
+    let p = [
+        Vect3::new(0.0, 0.0, 0.0),
+        Vect3::new(1.0, 2.0, 0.0),
+        Vect3::new(3.0, 1.0, 1.0),
+        Vect3::new(4.0, 0.0, 2.0),
+    ];
+    assert_eq!(Vect3::catmull_rom(&p[0], &p[1], &p[2], &p[3], 0.0), p[1]);
+    assert_eq!(Vect3::catmull_rom(&p[0], &p[1], &p[2], &p[3], 1.0), p[2]);
+}
+
+#[test]
+fn test_catmull_rom_spline_collinear() {
+    let pts: Vec<Vect3> = (0..4).map(|i| Vect3::new(i as f32, 0.0, 0.0)).collect();
+    let curve = Vect3::catmull_rom_spline(&pts, 4);
+    assert_eq!(curve.len(), 13);
+    assert_eq!(curve[0], pts[0]);
+    assert_eq!(curve[12], pts[3]);
+    for w in curve.windows(2) {
+        assert_eq!(w[0].y, 0.0);
+        assert_eq!(w[0].z, 0.0);
+        assert!(w[1].x > w[0].x);
+    }
+}
+
+#[test]
+fn test_catmull_rom_spline_short_input() {
+    let one = [Vect3::new(1.0, 2.0, 3.0)];
+    assert_eq!(Vect3::catmull_rom_spline(&one, 8), one.to_vec());
+}
+
+// --- Masks ---
+#[test]
+fn test_eq_mask() {
+    let a = Vect3::new(1.0, 2.0, 3.0);
+    let b = Vect3::new(1.0, 9.0, 3.0);
+    assert_eq!(a.eq_mask(&b), (true, false, true));
+}
+
+#[test]
+fn test_approx_eq_mask() {
+    let a = Vect3::new(1.0, 2.0, 3.0);
+    let b = Vect3::new(1.0 + 1e-7, 2.1, 3.0);
+    assert_eq!(a.eq_mask(&b), (false, false, true));
+    assert_eq!(a.approx_eq_mask(&b, EPS), (true, false, true));
+}
+
+#[test]
+fn test_select() {
+    let r = Vect3::select((true, false, true), &Vect3::splat(1.0), &Vect3::splat(2.0));
+    assert_eq!(r, Vect3::new(1.0, 2.0, 1.0));
+}
+
+#[test]
+fn test_select_with_eq_mask() {
+    let a = Vect3::new(1.0, 2.0, 3.0);
+    let b = Vect3::new(1.0, 9.0, 3.0);
+    let r = Vect3::select(a.eq_mask(&b), &Vect3::default(), &b);
+    assert_eq!(r, Vect3::new(0.0, 9.0, 0.0));
+}
+
+// --- OrdVect3 ---
+#[test]
+fn test_ord_vect3_hash_map_key() {
+    let mut map = HashMap::new();
+    map.insert(OrdVect3(Vect3::new(0.0, 1.0, 2.0)), "a");
+    map.insert(OrdVect3(Vect3::new(-0.0, 1.0, 2.0)), "b");
+    map.insert(OrdVect3(Vect3::new(f32::NAN, 0.0, 0.0)), "c");
+    map.insert(OrdVect3(Vect3::new(-f32::NAN, 0.0, 0.0)), "d");
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&OrdVect3(Vect3::new(0.0, 1.0, 2.0))], "b");
+    assert_eq!(map[&OrdVect3(Vect3::new(f32::NAN, -0.0, 0.0))], "d");
+}
+
+#[test]
+fn test_ord_vect3_ordering() {
+    let mut v = vec![
+        OrdVect3(Vect3::new(1.0, 0.0, 0.0)),
+        OrdVect3(Vect3::new(0.0, 5.0, 0.0)),
+        OrdVect3(Vect3::new(0.0, 1.0, 9.0)),
+    ];
+    v.sort();
+    let sorted: Vec<Vect3> = v.into_iter().map(Vect3::from).collect();
+    assert_eq!(
+        sorted,
+        vec![
+            Vect3::new(0.0, 1.0, 9.0),
+            Vect3::new(0.0, 5.0, 0.0),
+            Vect3::new(1.0, 0.0, 0.0),
+        ]
+    );
+}
+
+// --- Statistics ---
+#[test]
+fn test_mean_symmetric() {
+    let pts = [
+        Vect3::new(1.0, 2.0, -3.0),
+        Vect3::new(-1.0, -2.0, 3.0),
+        Vect3::new(4.0, 0.0, 1.0),
+        Vect3::new(-4.0, 0.0, -1.0),
+    ];
+    assert_eq!(Vect3::mean(&pts), Vect3::default());
+    assert_eq!(Vect3::mean(&[]), Vect3::default());
+}
+
+#[test]
+fn test_stable_centroid_matches_mean() {
+    let pts = [
+        Vect3::new(1.0, 2.0, 3.0),
+        Vect3::new(-4.0, 0.5, 9.0),
+        Vect3::new(2.5, -1.0, 0.0),
+        Vect3::new(0.5, 6.5, -4.0),
+    ];
+    assert!(Vect3::stable_centroid(&pts).distance(&Vect3::mean(&pts)) < EPS);
+    assert_eq!(Vect3::stable_centroid(&[]), Vect3::default());
+}
+
+#[test]
+fn test_stable_centroid_extreme() {
+    let big = Vect3::splat(f32::MAX);
+    let pts = [big, big * 0.5, big];
+    let c = Vect3::stable_centroid(&pts);
+    assert!(c.x.is_finite() && c.y.is_finite() && c.z.is_finite());
+    assert!((c.x / f32::MAX - 2.5 / 3.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_stable_centroid_mixed_sign_extreme() {
+    let pts = [Vect3::splat(f32::MAX), Vect3::splat(-f32::MAX)];
+    assert_eq!(Vect3::stable_centroid(&pts), Vect3::default());
+
+    let pts = [
+        Vect3::new(f32::MAX, -f32::MAX, 1.0),
+        Vect3::new(-f32::MAX, f32::MAX, 2.0),
+        Vect3::new(f32::MAX, f32::MAX, 3.0),
+    ];
+    let c = Vect3::stable_centroid(&pts);
+    assert!((c.x / f32::MAX - 1.0 / 3.0).abs() < 1e-6);
+    assert!((c.y / f32::MAX - 1.0 / 3.0).abs() < 1e-6);
+    assert!((c.z - 2.0).abs() < EPS);
+}
+
+#[test]
+fn test_variance() {
+    let pts = [
+        Vect3::new(-1.0, 0.0, 2.0),
+        Vect3::new(1.0, 0.0, 2.0),
+        Vect3::new(-1.0, 3.0, 2.0),
+        Vect3::new(1.0, 3.0, 2.0),
+    ];
+    assert_eq!(Vect3::variance(&pts), Vect3::new(1.0, 2.25, 0.0));
+    assert_eq!(Vect3::variance(&pts[..1]), Vect3::default());
+}
+
+// --- Numerical differentiation ---
+#[test]
+fn test_central_difference_length_squared() {
+    let at = Vect3::new(1.0, 2.0, 3.0);
+    let g = Vect3::central_difference(|v| v.length_squared(), at, 1e-2);
+    assert!(g.distance(&(at * 2.0)) < 1e-3);
+}
+
+// --- Packing ---
+#[test]
+fn test_snorm8_round_trip() {
+    let n = Vect3::new(1.0, -2.0, 0.5).normalize();
+    let back = Vect3::from_snorm8(n.to_snorm8());
+    assert!(back.distance(&n) < 1.0 / 127.0);
+    assert_eq!(Vect3::new(1.0, -1.0, 0.0).to_snorm8(), [127, -127, 0]);
+}
+
+#[test]
+fn test_snorm8_clamps() {
+    assert_eq!(Vect3::new(5.0, -5.0, 0.5).to_snorm8(), [127, -127, 64]);
+    assert_eq!(
+        Vect3::from_snorm8([-128, 127, 0]),
+        Vect3::new(-1.0, 1.0, 0.0)
+    );
+}
+
+#[test]
+fn test_unorm8() {
+    let c = Vect3::new(-0.5, 0.5, 2.0);
+    assert_eq!(c.to_unorm8(), [0, 128, 255]);
+    let back = Vect3::from_unorm8([0, 128, 255]);
+    assert!(back.distance(&Vect3::new(0.0, 0.5, 1.0)) < 1.0 / 255.0);
+}
+
+#[test]
+fn test_rgb10a2_round_trip() {
+    let n = Vect3::new(1.0, -2.0, 0.5).normalize();
+    let (back, a) = Vect3::from_rgb10a2(n.to_rgb10a2(3));
+    assert_eq!(a, 3);
+    assert!((back.x - n.x).abs() <= 0.5 / 511.0 + EPS);
+    assert!((back.y - n.y).abs() <= 0.5 / 511.0 + EPS);
+    assert!((back.z - n.z).abs() <= 0.5 / 511.0 + EPS);
+
+    let packed = Vect3::new(1.0, -1.0, 0.0).to_rgb10a2(1);
+    assert_eq!(packed, 0x1ff | (0x201 << 10) | (1 << 30));
+    assert_eq!(Vect3::from_rgb10a2(packed), (Vect3::new(1.0, -1.0, 0.0), 1));
+    // -512 is the one code with no positive mirror
+    assert_eq!(Vect3::from_rgb10a2(0x200).0.x, -1.0);
+}
+
+// --- Rounding ---
+#[test]
+fn test_round() {
+    assert_eq!(
+        Vect3::new(0.5, 1.5, -2.5).round(),
+        Vect3::new(1.0, 2.0, -3.0)
+    );
+}
+
+#[test]
+fn test_round_ties_even() {
+    assert_eq!(
+        Vect3::new(0.5, 1.5, 2.5).round_ties_even(),
+        Vect3::new(0.0, 2.0, 2.0)
+    );
+}
+
+#[test]
+fn test_floor_and_round_to_i32() {
+    let v = Vect3::new(1.9, -0.1, 2.5);
+    assert_eq!(v.floor_to_i32(), [1, -1, 2]);
+    assert_eq!(v.round_to_i32(), [2, 0, 3]);
+    assert_eq!(
+        Vect3::new(1e20, -1e20, f32::NAN).floor_to_i32(),
+        [i32::MAX, i32::MIN, 0]
+    );
+}
+
+// --- Exponentials ---
+#[test]
+fn test_exp2_log2() {
+    let v = Vect3::new(1.0, 2.0, 3.0);
+    assert_eq!(v.exp2(), Vect3::new(2.0, 4.0, 8.0));
+    assert_eq!(v.exp2().log2(), v);
+}
+
+#[test]
+#[should_panic(expected = "Vect3::log2 produced non-finite result")]
+fn test_log2_zero() {
+    let _ = Vect3::new(0.0, 1.0, 1.0).log2();
+}
+
+// --- Flattening ---
+#[test]
+fn test_to_flat_vec() {
+    let vs = [Vect3::new(1.0, 2.0, 3.0), Vect3::new(4.0, 5.0, 6.0)];
+    let flat = Vect3::to_flat_vec(&vs);
+    assert_eq!(flat.len(), 3 * vs.len());
+    assert_eq!(flat, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert!(Vect3::to_flat_vec(&[]).is_empty());
+}
+
+#[test]
+fn test_array_vec_round_trip() {
+    let vs = vec![
+        Vect3::new(1.0, 2.0, 3.0),
+        Vect3::new(-4.0, 5.0, 6.5),
+        Vect3::new(0.0, 0.0, -1.0),
+    ];
+    let arrays = Vect3::to_array_vec(&vs);
+    assert_eq!(arrays[1], [-4.0, 5.0, 6.5]);
+    assert_eq!(Vect3::from_array_slice(&arrays), vs);
+}
+
+// --- Shader helpers ---
+#[test]
+fn test_step() {
+    let edge = Vect3::splat(0.5);
+    let x = Vect3::new(0.25, 0.5, 0.75);
+    assert_eq!(Vect3::step(&edge, &x), Vect3::new(0.0, 1.0, 1.0));
+}
+
+#[test]
+fn test_smoothstep() {
+    let e0 = Vect3::splat(0.0);
+    let e1 = Vect3::splat(2.0);
+    let x = Vect3::new(-1.0, 0.5, 3.0);
+    assert_eq!(
+        Vect3::smoothstep(&e0, &e1, &x),
+        Vect3::new(0.0, 0.15625, 1.0)
+    );
+    let mid = Vect3::smoothstep(&e0, &e1, &Vect3::splat(1.0));
+    assert_eq!(mid, Vect3::splat(0.5));
+}
+
+#[test]
+fn test_fresnel_schlick() {
+    let f0 = Vect3::new(0.04, 0.5, 0.9);
+    assert_eq!(Vect3::fresnel_schlick(1.0, &f0), f0);
+    assert_eq!(Vect3::fresnel_schlick(0.0, &f0), Vect3::splat(1.0));
+    let half = Vect3::fresnel_schlick(0.5, &Vect3::splat(0.0));
+    assert_eq!(half, Vect3::splat(0.03125));
+}
+
+#[test]
+fn test_flip() {
+    let v = Vect3::new(1.0, 2.0, 3.0);
+    assert_eq!(v.flip(true, false, true), Vect3::new(-1.0, 2.0, -3.0));
+    assert_eq!(v.flip(false, false, false), v);
+}
@@ -0,0 +1,42 @@
+use tiny_vect::{Vect2, Vect3, Vector, lerp};
+
+// One centroid routine for either vector type
+fn centroid<V: Vector>(pts: &[V]) -> V {
+    let sum = pts.iter().fold(V::ZERO, |acc, &p| acc + p);
+    sum / pts.len() as f32
+}
+
+fn unit_midpoint<V: Vector>(a: V, b: V) -> V {
+    lerp(a, b, 0.5).normalize()
+}
+
+#[test]
+fn test_generic_centroid() {
+    let square = [
+        Vect2::new(0.0, 0.0),
+        Vect2::new(2.0, 0.0),
+        Vect2::new(2.0, 2.0),
+        Vect2::new(0.0, 2.0),
+    ];
+    assert_eq!(centroid(&square), Vect2::splat(1.0));
+
+    let tri = [
+        Vect3::new(3.0, 0.0, 0.0),
+        Vect3::new(0.0, 3.0, 0.0),
+        Vect3::new(0.0, 0.0, 3.0),
+    ];
+    assert_eq!(centroid(&tri), <Vect3 as Vector>::splat(1.0));
+}
+
+#[test]
+fn test_generic_dot_length_normalize() {
+    let m2 = unit_midpoint(Vect2::new(1.0, 0.0), Vect2::new(0.0, 1.0));
+    assert!((m2.length() - 1.0).abs() < 1e-6);
+    let m3 = unit_midpoint(Vect3::new(2.0, 0.0, 0.0), Vect3::new(0.0, 0.0, 2.0));
+    assert!((Vector::dot(&m3, &Vect3::new(1.0, 0.0, 1.0)) - 2.0f32.sqrt()).abs() < 1e-6);
+    assert_eq!(<Vect2 as Vector>::ZERO, Vect2::default());
+    assert_eq!(
+        <Vect3 as Vector>::ZERO - Vect3::splat(1.0),
+        Vect3::splat(-1.0)
+    );
+}